@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use classic_mceliece::{
+    ClassicMcEliece, McEliece348864, McEliece460896, McEliece6688128, McEliece6960119,
+    McEliece8192128, ParameterSet,
+};
+
+fn decapsulate<P: ParameterSet>(c: &mut Criterion, name: &str) {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+    let (ciphertext, _) = public_key.encapsulate();
+
+    c.benchmark_group("decapsulate").bench_function(name, |b| {
+        b.iter(|| secret_key.decapsulate(black_box(&ciphertext)))
+    });
+}
+
+fn benchmarks(c: &mut Criterion) {
+    decapsulate::<McEliece348864>(c, "mceliece348864");
+    decapsulate::<McEliece460896>(c, "mceliece460896");
+    decapsulate::<McEliece6688128>(c, "mceliece6688128");
+    decapsulate::<McEliece6960119>(c, "mceliece6960119");
+    decapsulate::<McEliece8192128>(c, "mceliece8192128");
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use std::env;
+use std::process;
+
+use classic_mceliece::dudect::{self, THRESHOLD};
+use classic_mceliece::{McEliece348864, McEliece348864f, McEliece6960119, McEliece8192128};
+
+type Test = (&'static str, fn() -> f64);
+
+fn tests() -> Vec<Test> {
+    vec![
+        ("inverse/mceliece348864", || {
+            dudect::inverse::<McEliece348864>(100_000)
+        }),
+        ("inverse/mceliece8192128", || {
+            dudect::inverse::<McEliece8192128>(100_000)
+        }),
+        ("berlenkamp_massey/mceliece348864", || {
+            dudect::berlenkamp_massey::<McEliece348864>(20_000)
+        }),
+        ("berlenkamp_massey/mceliece8192128", || {
+            dudect::berlenkamp_massey::<McEliece8192128>(20_000)
+        }),
+        ("fixed_weight/accepting/mceliece348864", || {
+            dudect::fixed_weight::<McEliece348864>(100_000, true)
+        }),
+        ("fixed_weight/rejecting/mceliece348864", || {
+            dudect::fixed_weight::<McEliece348864>(100_000, false)
+        }),
+        ("fixed_weight/accepting/mceliece8192128", || {
+            dudect::fixed_weight::<McEliece8192128>(100_000, true)
+        }),
+        ("decode/mceliece348864", || {
+            dudect::decode::<McEliece348864>(10_000)
+        }),
+        ("decode/mceliece8192128", || {
+            dudect::decode::<McEliece8192128>(10_000)
+        }),
+        ("decapsulate/mceliece348864", || {
+            dudect::decapsulate::<McEliece348864>(10_000)
+        }),
+        ("decapsulate/mceliece6960119", || {
+            dudect::decapsulate::<McEliece6960119>(5_000)
+        }),
+        ("decapsulate/mceliece8192128", || {
+            dudect::decapsulate::<McEliece8192128>(5_000)
+        }),
+        ("irreducible/mceliece348864", || {
+            dudect::irreducible::<McEliece348864>(10_000)
+        }),
+        ("irreducible/mceliece8192128", || {
+            dudect::irreducible::<McEliece8192128>(2_000)
+        }),
+        ("public_key_generate/rejecting/mceliece348864", || {
+            dudect::public_key_generate::<McEliece348864>(1_000, false)
+        }),
+        ("public_key_generate/accepting/mceliece348864f", || {
+            dudect::public_key_generate::<McEliece348864f>(1_000, true)
+        }),
+    ]
+}
+
+// Runs the tests whose names contain any of the arguments, or all of them, and exits with an
+// error if any leaks.
+fn main() {
+    // `cargo bench` passes `--bench`.
+    let filters: Vec<String> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect();
+
+    let mut leaks = 0;
+
+    for (name, test) in tests()
+        .into_iter()
+        .filter(|(name, _)| filters.is_empty() || filters.iter().any(|f| name.contains(f.as_str())))
+    {
+        let max_t = test();
+        let verdict = if max_t < THRESHOLD {
+            "ok"
+        } else {
+            leaks += 1;
+            "LEAK"
+        };
+        println!("{:48} max |t| = {:6.2}  {}", name, max_t, verdict);
+    }
+
+    if leaks > 0 {
+        println!("{} of the tests exceed |t| = {}", leaks, THRESHOLD);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,57 @@
+// Writes valid serialized keys and ciphertexts to corpus/, which cargo fuzz uses as the seed corpus
+// of each target. Random inputs never have the length of a key, let alone parse as one, so without
+// these seeds the key targets only reach `Error::InvalidLength`. Keys are also far longer than
+// libFuzzer's default 4096-byte inputs, so raise the maximum length to the largest key:
+//
+//     cargo run --release --example seed_corpus
+//     cargo +nightly fuzz run secret_key_from_bytes -- -max_len=14120
+//     cargo +nightly fuzz run public_key_from_bytes -- -max_len=1357824
+
+use std::fs;
+use std::path::Path;
+
+use classic_mceliece::*;
+
+fn write(target: &str, name: &str, bytes: &[u8]) {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("corpus")
+        .join(target);
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join(name), bytes).unwrap();
+}
+
+fn seeds<P: ParameterSet>(name: &str) {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair_from_seed(&[1; 32]);
+
+    write(
+        "secret_key_from_bytes",
+        name,
+        secret_key.to_bytes().as_ref(),
+    );
+    write("public_key_from_bytes", name, public_key.as_ref());
+}
+
+// Ciphertexts for the keys the decapsulate target uses, generated from the zero seed.
+fn ciphertext<P: ParameterSet>(name: &str) {
+    let (_, public_key) = ClassicMcEliece::<P>::generate_keypair_from_seed(&[0; 32]);
+    let (ciphertext, _) = public_key.encapsulate();
+
+    write("decapsulate", name, ciphertext.as_ref());
+}
+
+fn main() {
+    seeds::<McEliece348864>("mceliece348864");
+    seeds::<McEliece348864f>("mceliece348864f");
+    seeds::<McEliece460896>("mceliece460896");
+    seeds::<McEliece460896f>("mceliece460896f");
+    seeds::<McEliece6688128>("mceliece6688128");
+    seeds::<McEliece6688128f>("mceliece6688128f");
+    seeds::<McEliece6960119>("mceliece6960119");
+    seeds::<McEliece6960119f>("mceliece6960119f");
+    seeds::<McEliece8192128>("mceliece8192128");
+    seeds::<McEliece8192128f>("mceliece8192128f");
+
+    ciphertext::<McEliece348864>("mceliece348864");
+    ciphertext::<McEliece6960119>("mceliece6960119");
+    ciphertext::<McEliece8192128f>("mceliece8192128f");
+}
@@ -0,0 +1,27 @@
+#![no_main]
+
+use std::convert::TryFrom;
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+
+use classic_mceliece::*;
+
+static MCELIECE348864: OnceLock<SecretKey<McEliece348864>> = OnceLock::new();
+static MCELIECE6960119: OnceLock<SecretKey<McEliece6960119>> = OnceLock::new();
+static MCELIECE8192128F: OnceLock<SecretKey<McEliece8192128f>> = OnceLock::new();
+
+// Any ciphertext of the right length decapsulates, to the session key or an implicit rejection.
+fn decapsulate<P: ParameterSet>(secret_key: &OnceLock<SecretKey<P>>, data: &[u8]) {
+    if let Ok(ciphertext) = Ciphertext::<P>::try_from(data) {
+        let secret_key = secret_key.get_or_init(|| SecretKey::from_seed(&[0; 32]));
+        let session_key = secret_key.decapsulate(&ciphertext);
+        assert_eq!(session_key.as_ref().len(), 32);
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    decapsulate(&MCELIECE348864, data);
+    decapsulate(&MCELIECE6960119, data);
+    decapsulate(&MCELIECE8192128F, data);
+});
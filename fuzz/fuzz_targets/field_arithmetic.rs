@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use classic_mceliece::fuzzing;
+use classic_mceliece::{McEliece348864, McEliece8192128};
+
+// McEliece348864 has GF(2^12); the other parameter sets share the GF(2^13) of McEliece8192128.
+fuzz_target!(|input: (u16, u16)| {
+    let (a, b) = input;
+    fuzzing::field_arithmetic::<McEliece348864>(a, b);
+    fuzzing::field_arithmetic::<McEliece8192128>(a, b);
+});
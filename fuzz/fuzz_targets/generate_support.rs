@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use classic_mceliece::fuzzing;
+use classic_mceliece::*;
+
+fuzz_target!(|data: &[u8]| {
+    let (selector, bytes) = match data.split_first() {
+        Some((selector, bytes)) => (*selector, bytes),
+        None => return,
+    };
+
+    match selector % 5 {
+        0 => fuzzing::generate_support::<McEliece348864>(bytes),
+        1 => fuzzing::generate_support::<McEliece460896>(bytes),
+        2 => fuzzing::generate_support::<McEliece6688128>(bytes),
+        3 => fuzzing::generate_support::<McEliece6960119>(bytes),
+        _ => fuzzing::generate_support::<McEliece8192128>(bytes),
+    }
+});
@@ -0,0 +1,30 @@
+#![no_main]
+
+use std::convert::TryFrom;
+
+use libfuzzer_sys::fuzz_target;
+
+use classic_mceliece::*;
+
+// Whatever parses must serialize back to the same bytes, and decapsulate without panicking.
+fn from_bytes<P: ParameterSet>(data: &[u8]) {
+    if let Ok(secret_key) = SecretKey::<P>::try_from(data) {
+        assert_eq!(secret_key.to_bytes().as_ref(), data);
+
+        let ciphertext = Ciphertext::<P>::try_from(&vec![0; Ciphertext::<P>::BYTES][..]).unwrap();
+        secret_key.decapsulate(&ciphertext);
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    from_bytes::<McEliece348864>(data);
+    from_bytes::<McEliece348864f>(data);
+    from_bytes::<McEliece460896>(data);
+    from_bytes::<McEliece460896f>(data);
+    from_bytes::<McEliece6688128>(data);
+    from_bytes::<McEliece6688128f>(data);
+    from_bytes::<McEliece6960119>(data);
+    from_bytes::<McEliece6960119f>(data);
+    from_bytes::<McEliece8192128>(data);
+    from_bytes::<McEliece8192128f>(data);
+});
@@ -1,4 +1,5 @@
 #!/bin/sh -eu
 export RUST_BACKTRACE=1
-cargo test           -- --nocapture
-cargo test --release -- --nocapture --ignored
+cargo test --all-features           -- --nocapture
+[ -d tests/kats ] || tests/fetch-kats.sh
+cargo test --all-features --release -- --nocapture --ignored
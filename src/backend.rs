@@ -0,0 +1,242 @@
+// The hot loops, with an AVX2 implementation selected at runtime where the CPU supports it. Both
+// implementations are constant-time and give identical output.
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod avx2;
+mod portable;
+
+use crate::field_element::FieldElement;
+use crate::parameter_set::ParameterSet;
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+#[inline]
+fn has_avx2() -> bool {
+    is_x86_feature_detected!("avx2")
+}
+
+// dst ^= src & mask, bytewise.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn xor_masked(dst: &mut [u8], src: &[u8], mask: u8) {
+    debug_assert_eq!(dst.len(), src.len());
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2, as just detected at runtime.
+        return unsafe { avx2::xor_masked(dst, src, mask) };
+    }
+
+    portable::xor_masked(dst, src, mask)
+}
+
+// Returns the XOR of all bytes of lhs & rhs.
+#[inline]
+pub(crate) fn and_xor_fold(lhs: &[u8], rhs: &[u8]) -> u8 {
+    debug_assert_eq!(lhs.len(), rhs.len());
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2, as just detected at runtime.
+        return unsafe { avx2::and_xor_fold(lhs, rhs) };
+    }
+
+    portable::and_xor_fold(lhs, rhs)
+}
+
+// Swaps the bits of lhs[j] and rhs[j] selected by the little-endian u64 at condition_bits[8j..].
+#[inline]
+pub(crate) fn conditional_swap(lhs: &mut [u64], rhs: &mut [u64], condition_bits: &[u8]) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    debug_assert_eq!(condition_bits.len(), 8 * lhs.len());
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2, as just detected at runtime.
+        return unsafe { avx2::conditional_swap(lhs, rhs, condition_bits) };
+    }
+
+    portable::conditional_swap(lhs, rhs, condition_bits)
+}
+
+// Swaps bits (i + j, i + j + s) of each word for every block i of 2s bits, consuming 32 condition
+// bits per word; s < 64.
+#[inline]
+pub(crate) fn conditional_swap_within(data: &mut [u64], condition_bits: &[u8], s: usize) {
+    debug_assert!(s < 64);
+    debug_assert_eq!(condition_bits.len(), 4 * data.len());
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2, as just detected at runtime.
+        return unsafe { avx2::conditional_swap_within(data, condition_bits, s) };
+    }
+
+    portable::conditional_swap_within(data, condition_bits, s)
+}
+
+// dst += lhs * rhs, elementwise.
+#[inline]
+pub(crate) fn add_products<P: ParameterSet>(
+    dst: &mut [FieldElement<P>],
+    lhs: &[FieldElement<P>],
+    rhs: &[FieldElement<P>],
+) {
+    debug_assert_eq!(dst.len(), lhs.len());
+    debug_assert_eq!(dst.len(), rhs.len());
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2, as just detected at runtime.
+        return unsafe { avx2::add_products(dst, lhs, rhs) };
+    }
+
+    portable::add_products(dst, lhs, rhs)
+}
+
+#[cfg(all(test, feature = "std", target_arch = "x86_64"))]
+mod tests {
+    use rand::Rng;
+
+    use crate::backend::{avx2, has_avx2, portable};
+    use crate::field_element::FieldElement;
+    use crate::parameter_set::*;
+
+    const LENGTHS: [usize; 6] = [0, 1, 7, 32, 100, 1027];
+
+    fn random_bytes(len: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; len];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    // Without AVX2 there is nothing to compare against; say so rather than silently passing.
+    fn skip_without_avx2(test: &str) -> bool {
+        if has_avx2() {
+            return false;
+        }
+        eprintln!("skipping {}: the CPU does not support AVX2", test);
+        true
+    }
+
+    fn random_words(len: usize) -> Vec<u64> {
+        let mut words = vec![0u64; len];
+        rand::thread_rng().fill(&mut words[..]);
+        words
+    }
+
+    #[test]
+    fn xor_masked() {
+        if skip_without_avx2("xor_masked") {
+            return;
+        }
+
+        for len in LENGTHS {
+            let src = random_bytes(len);
+            for mask in [0x00, 0xff, 0x5a] {
+                let mut expected = random_bytes(len);
+                let mut dst = expected.clone();
+
+                portable::xor_masked(&mut expected, &src, mask);
+                // SAFETY: AVX2 support was checked above.
+                unsafe { avx2::xor_masked(&mut dst, &src, mask) };
+
+                assert_eq!(dst, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn and_xor_fold() {
+        if skip_without_avx2("and_xor_fold") {
+            return;
+        }
+
+        for len in LENGTHS {
+            let (lhs, rhs) = (random_bytes(len), random_bytes(len));
+
+            // SAFETY: AVX2 support was checked above.
+            assert_eq!(
+                unsafe { avx2::and_xor_fold(&lhs, &rhs) },
+                portable::and_xor_fold(&lhs, &rhs)
+            );
+        }
+    }
+
+    #[test]
+    fn conditional_swap() {
+        if skip_without_avx2("conditional_swap") {
+            return;
+        }
+
+        for len in LENGTHS {
+            let condition_bits = random_bytes(8 * len);
+
+            let (mut expected_lhs, mut expected_rhs) = (random_words(len), random_words(len));
+            let (mut lhs, mut rhs) = (expected_lhs.clone(), expected_rhs.clone());
+
+            portable::conditional_swap(&mut expected_lhs, &mut expected_rhs, &condition_bits);
+            // SAFETY: AVX2 support was checked above.
+            unsafe { avx2::conditional_swap(&mut lhs, &mut rhs, &condition_bits) };
+
+            assert_eq!(lhs, expected_lhs);
+            assert_eq!(rhs, expected_rhs);
+        }
+    }
+
+    #[test]
+    fn conditional_swap_within() {
+        if skip_without_avx2("conditional_swap_within") {
+            return;
+        }
+
+        for len in LENGTHS {
+            let condition_bits = random_bytes(4 * len);
+
+            for s in [1, 2, 4, 8, 16, 32] {
+                let mut expected = random_words(len);
+                let mut data = expected.clone();
+
+                portable::conditional_swap_within(&mut expected, &condition_bits, s);
+                // SAFETY: AVX2 support was checked above.
+                unsafe { avx2::conditional_swap_within(&mut data, &condition_bits, s) };
+
+                assert_eq!(data, expected);
+            }
+        }
+    }
+
+    fn add_products<P: ParameterSet>() {
+        if skip_without_avx2("add_products") {
+            return;
+        }
+
+        let random_elements = |len| -> Vec<FieldElement<P>> {
+            (0..len)
+                .map(|_| rand::thread_rng().gen::<u16>().into())
+                .collect()
+        };
+
+        for len in LENGTHS {
+            let (lhs, rhs) = (random_elements(len), random_elements(len));
+
+            let mut expected = random_elements(len);
+            let mut dst = expected.clone();
+
+            portable::add_products(&mut expected, &lhs, &rhs);
+            // SAFETY: AVX2 support was checked above.
+            unsafe { avx2::add_products(&mut dst, &lhs, &rhs) };
+
+            assert_eq!(dst, expected);
+        }
+    }
+
+    #[test]
+    fn add_products_mceliece348864() {
+        add_products::<McEliece348864>();
+    }
+
+    #[test]
+    fn add_products_mceliece8192128() {
+        add_products::<McEliece8192128>();
+    }
+}
@@ -0,0 +1,164 @@
+// Each function is unsafe only because it requires AVX2, which the callers in `backend` detect at
+// runtime. The loads and stores are unaligned and each covers exactly one chunk of a
+// `chunks_exact` iterator, so they stay within the slices; remainders go to the portable code.
+
+use core::arch::x86_64::*;
+
+use crate::backend::portable;
+use crate::field_element::FieldElement;
+use crate::parameter_set::ParameterSet;
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn xor_masked(dst: &mut [u8], src: &[u8], mask: u8) {
+    let mask_vector = _mm256_set1_epi8(mask as i8);
+
+    let mut dst = dst.chunks_exact_mut(32);
+    let mut src = src.chunks_exact(32);
+
+    for (d, s) in (&mut dst).zip(&mut src) {
+        // SAFETY: d and s are 32 bytes each.
+        let d_vector = _mm256_loadu_si256(d.as_ptr() as *const __m256i);
+        let s_vector = _mm256_loadu_si256(s.as_ptr() as *const __m256i);
+        let d_vector = _mm256_xor_si256(d_vector, _mm256_and_si256(s_vector, mask_vector));
+        _mm256_storeu_si256(d.as_mut_ptr() as *mut __m256i, d_vector);
+    }
+
+    portable::xor_masked(dst.into_remainder(), src.remainder(), mask);
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn and_xor_fold(lhs: &[u8], rhs: &[u8]) -> u8 {
+    let mut acc = _mm256_setzero_si256();
+
+    let mut lhs = lhs.chunks_exact(32);
+    let mut rhs = rhs.chunks_exact(32);
+
+    for (l, r) in (&mut lhs).zip(&mut rhs) {
+        // SAFETY: l and r are 32 bytes each.
+        let l_vector = _mm256_loadu_si256(l.as_ptr() as *const __m256i);
+        let r_vector = _mm256_loadu_si256(r.as_ptr() as *const __m256i);
+        acc = _mm256_xor_si256(acc, _mm256_and_si256(l_vector, r_vector));
+    }
+
+    let mut bytes = [0u8; 32];
+    // SAFETY: bytes is 32 bytes.
+    _mm256_storeu_si256(bytes.as_mut_ptr() as *mut __m256i, acc);
+
+    bytes.iter().fold(0, |acc, b| acc ^ b)
+        ^ portable::and_xor_fold(lhs.remainder(), rhs.remainder())
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn conditional_swap(lhs: &mut [u64], rhs: &mut [u64], condition_bits: &[u8]) {
+    let mut lhs = lhs.chunks_exact_mut(4);
+    let mut rhs = rhs.chunks_exact_mut(4);
+    let mut condition_bits = condition_bits.chunks_exact(32);
+
+    for ((l, r), condition) in (&mut lhs).zip(&mut rhs).zip(&mut condition_bits) {
+        // SAFETY: l and r are four u64s and condition is 32 bytes, so all three are 32 bytes.
+        let l_vector = _mm256_loadu_si256(l.as_ptr() as *const __m256i);
+        let r_vector = _mm256_loadu_si256(r.as_ptr() as *const __m256i);
+        let condition = _mm256_loadu_si256(condition.as_ptr() as *const __m256i);
+
+        let swap_operator = _mm256_and_si256(_mm256_xor_si256(l_vector, r_vector), condition);
+
+        _mm256_storeu_si256(
+            l.as_mut_ptr() as *mut __m256i,
+            _mm256_xor_si256(l_vector, swap_operator),
+        );
+        _mm256_storeu_si256(
+            r.as_mut_ptr() as *mut __m256i,
+            _mm256_xor_si256(r_vector, swap_operator),
+        );
+    }
+
+    portable::conditional_swap(
+        lhs.into_remainder(),
+        rhs.into_remainder(),
+        condition_bits.remainder(),
+    );
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn conditional_swap_within(data: &mut [u64], condition_bits: &[u8], s: usize) {
+    let low_bits = _mm256_set1_epi64x((1 << s) - 1);
+    let s_count = _mm_cvtsi32_si128(s as i32);
+
+    let mut data = data.chunks_exact_mut(4);
+    let mut condition_bits = condition_bits.chunks_exact(16);
+
+    for (d, condition) in (&mut data).zip(&mut condition_bits) {
+        // SAFETY: condition is 16 bytes and d is four u64s, 32 bytes.
+        let condition =
+            _mm256_cvtepu32_epi64(_mm_loadu_si128(condition.as_ptr() as *const __m128i));
+
+        let mut selected_bits = _mm256_setzero_si256();
+        for k in 0..(32 / s) {
+            let bits = _mm256_srl_epi64(condition, _mm_cvtsi32_si128((k * s) as i32));
+            let bits = _mm256_and_si256(bits, low_bits);
+            let bits = _mm256_sll_epi64(bits, _mm_cvtsi32_si128((2 * k * s) as i32));
+            selected_bits = _mm256_or_si256(selected_bits, bits);
+        }
+
+        let d_vector = _mm256_loadu_si256(d.as_ptr() as *const __m256i);
+
+        let swap_operator = _mm256_xor_si256(d_vector, _mm256_srl_epi64(d_vector, s_count));
+        let swap_operator = _mm256_and_si256(swap_operator, selected_bits);
+
+        let d_vector = _mm256_xor_si256(
+            d_vector,
+            _mm256_xor_si256(swap_operator, _mm256_sll_epi64(swap_operator, s_count)),
+        );
+        _mm256_storeu_si256(d.as_mut_ptr() as *mut __m256i, d_vector);
+    }
+
+    portable::conditional_swap_within(data.into_remainder(), condition_bits.remainder(), s);
+}
+
+// Multiplies eight pairs of field elements at a time in 32-bit lanes.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn add_products<P: ParameterSet>(
+    dst: &mut [FieldElement<P>],
+    lhs: &[FieldElement<P>],
+    rhs: &[FieldElement<P>],
+) {
+    let polynomial = (1 << P::M) | P::FIELD_POLYNOMIAL as i32;
+    let one = _mm256_set1_epi32(1);
+
+    let mut dst = dst.chunks_exact_mut(8);
+    let mut lhs = lhs.chunks_exact(8);
+    let mut rhs = rhs.chunks_exact(8);
+
+    for ((d, l), r) in (&mut dst).zip(&mut lhs).zip(&mut rhs) {
+        // SAFETY: d, l and r are eight FieldElements each, which are transparent u16s, so 16
+        // bytes each.
+        let l_vector = _mm256_cvtepu16_epi32(_mm_loadu_si128(l.as_ptr() as *const __m128i));
+        let r_vector = _mm256_cvtepu16_epi32(_mm_loadu_si128(r.as_ptr() as *const __m128i));
+
+        let mut x = _mm256_setzero_si256();
+
+        for bit in 0..P::M {
+            let count = _mm_cvtsi32_si128(bit as i32);
+            let mask = _mm256_cmpeq_epi32(
+                _mm256_and_si256(_mm256_srl_epi32(r_vector, count), one),
+                one,
+            );
+            x = _mm256_xor_si256(x, _mm256_and_si256(_mm256_sll_epi32(l_vector, count), mask));
+        }
+
+        for i in (P::M..(2 * P::M - 1)).rev() {
+            let count = _mm_cvtsi32_si128(i as i32);
+            let mask = _mm256_cmpeq_epi32(_mm256_and_si256(_mm256_srl_epi32(x, count), one), one);
+            let reduction = _mm256_set1_epi32(polynomial << (i - P::M));
+            x = _mm256_xor_si256(x, _mm256_and_si256(mask, reduction));
+        }
+
+        let x = _mm256_permute4x64_epi64(_mm256_packus_epi32(x, x), 0b1000);
+
+        let d_vector = _mm_loadu_si128(d.as_ptr() as *const __m128i);
+        let d_vector = _mm_xor_si128(d_vector, _mm256_castsi256_si128(x));
+        _mm_storeu_si128(d.as_mut_ptr() as *mut __m128i, d_vector);
+    }
+
+    portable::add_products(dst.into_remainder(), lhs.remainder(), rhs.remainder());
+}
@@ -0,0 +1,55 @@
+use core::convert::TryInto;
+
+use crate::field_element::FieldElement;
+use crate::parameter_set::ParameterSet;
+
+#[cfg(feature = "std")]
+pub(crate) fn xor_masked(dst: &mut [u8], src: &[u8], mask: u8) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        *d ^= s & mask;
+    }
+}
+
+pub(crate) fn and_xor_fold(lhs: &[u8], rhs: &[u8]) -> u8 {
+    lhs.iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| l & r)
+        .fold(0, |acc, b| acc ^ b)
+}
+
+pub(crate) fn conditional_swap(lhs: &mut [u64], rhs: &mut [u64], condition_bits: &[u8]) {
+    for ((l, r), condition) in lhs
+        .iter_mut()
+        .zip(rhs.iter_mut())
+        .zip(condition_bits.chunks_exact(8))
+    {
+        let condition = u64::from_le_bytes(condition.try_into().unwrap());
+        let swap_operator = (*l ^ *r) & condition;
+        *l ^= swap_operator;
+        *r ^= swap_operator;
+    }
+}
+
+pub(crate) fn conditional_swap_within(data: &mut [u64], condition_bits: &[u8], s: usize) {
+    for (data, condition_bits) in data.iter_mut().zip(condition_bits.chunks_exact(4)) {
+        let condition = u32::from_le_bytes(condition_bits.try_into().unwrap()) as u64;
+
+        let mut selected_bits = 0;
+        for k in 0..(32 / s) {
+            selected_bits |= ((condition >> (k * s)) & ((1 << s) - 1)) << (2 * k * s);
+        }
+
+        let swap_operator = (*data ^ (*data >> s)) & selected_bits;
+        *data ^= swap_operator ^ (swap_operator << s);
+    }
+}
+
+pub(crate) fn add_products<P: ParameterSet>(
+    dst: &mut [FieldElement<P>],
+    lhs: &[FieldElement<P>],
+    rhs: &[FieldElement<P>],
+) {
+    for (d, (l, r)) in dst.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+        *d += *l * *r;
+    }
+}
@@ -0,0 +1,149 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use zeroize::Zeroize;
+
+use crate::parameter_set::sealed::Sealed;
+
+// The storage of a `Buffer` without `std`: an array with room for the elements of one parameter set.
+pub trait Array<T: Copy> {
+    const LEN: usize;
+
+    fn filled(value: T) -> Self;
+
+    fn as_slice(&self) -> &[T];
+
+    fn as_mut_slice(&mut self) -> &mut [T];
+}
+
+impl<T: Copy, const N: usize> Array<T> for [T; N] {
+    const LEN: usize = N;
+
+    #[inline]
+    fn filled(value: T) -> Self {
+        [value; N]
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+// Buffers of Q, 2Q and N elements of the parameter set P.
+pub(crate) type QBuffer<P, T> = Buffer<T, <P as Sealed>::QArray<T>>;
+pub(crate) type DoubleQBuffer<P, T> = Buffer<T, <P as Sealed>::DoubleQArray<T>>;
+pub(crate) type NBuffer<P, T> = Buffer<T, <P as Sealed>::NArray<T>>;
+
+// A working buffer of up to A::LEN elements that is zeroized on drop. With `std` it lives on the
+// heap, so that the largest buffers fit small thread stacks; without it, on the stack, in an array
+// sized for the parameter set rather than for the largest one.
+pub(crate) struct Buffer<T: Copy + Zeroize, A: Array<T>> {
+    #[cfg(feature = "std")]
+    elements: Vec<T>,
+    #[cfg(not(feature = "std"))]
+    elements: A,
+    len: usize,
+    storage: PhantomData<(T, A)>,
+}
+
+impl<T: Copy + Zeroize, A: Array<T>> Buffer<T, A> {
+    #[inline]
+    pub(crate) fn new(value: T, len: usize) -> Self {
+        debug_assert!(len <= A::LEN);
+
+        Self {
+            #[cfg(feature = "std")]
+            elements: vec![value; len],
+            #[cfg(not(feature = "std"))]
+            elements: A::filled(value),
+            len,
+            storage: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn elements_mut(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn elements_mut(&mut self) -> &mut [T] {
+        self.elements.as_mut_slice()
+    }
+}
+
+impl<T: Copy + Zeroize, A: Array<T>> Deref for Buffer<T, A> {
+    type Target = [T];
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.elements[..self.len]
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.elements.as_slice()[..self.len]
+    }
+}
+
+impl<T: Copy + Zeroize, A: Array<T>> DerefMut for Buffer<T, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        let len = self.len;
+        &mut self.elements_mut()[..len]
+    }
+}
+
+impl<T: Copy + Zeroize + fmt::Debug, A: Array<T>> fmt::Debug for Buffer<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self[..].fmt(f)
+    }
+}
+
+impl<T: Copy + Zeroize, A: Array<T>> Drop for Buffer<T, A> {
+    fn drop(&mut self) {
+        self.elements_mut().iter_mut().for_each(Zeroize::zeroize);
+        self.len = 0;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::cell::Cell;
+
+    use zeroize::Zeroize;
+
+    use crate::buffer::Buffer;
+
+    thread_local! {
+        static ZEROIZED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // Counts the elements wiped, since the heap memory itself is freed by the drop.
+    #[derive(Clone, Copy)]
+    struct Element;
+
+    impl Zeroize for Element {
+        fn zeroize(&mut self) {
+            ZEROIZED.with(|zeroized| zeroized.set(zeroized.get() + 1));
+        }
+    }
+
+    #[test]
+    fn zeroized_on_drop() {
+        drop(Buffer::<Element, [Element; 8]>::new(Element, 5));
+
+        assert_eq!(ZEROIZED.with(Cell::get), 5);
+    }
+}
@@ -0,0 +1,74 @@
+use core::convert::TryFrom;
+
+use crate::error::Error;
+use crate::parameter_set::{ByteArray, ParameterSet};
+
+/// A ciphertext, the encapsulated key.
+#[derive(Clone, Copy, Debug)]
+pub struct Ciphertext<P: ParameterSet>(pub(crate) P::CiphertextBytes);
+
+impl<P: ParameterSet> Ciphertext<P> {
+    pub const BYTES: usize = P::CIPHERTEXT_BYTES;
+
+    #[inline]
+    pub fn from_bytes(input: &P::CiphertextBytes) -> Self {
+        Self(*input)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &P::CiphertextBytes {
+        &self.0
+    }
+}
+
+impl<P: ParameterSet> TryFrom<&[u8]> for Ciphertext<P> {
+    type Error = Error;
+
+    fn try_from(input: &[u8]) -> Result<Self, Error> {
+        if input.len() != Self::BYTES {
+            return Err(Error::InvalidLength);
+        }
+
+        let mut bytes = P::CiphertextBytes::zeroed();
+        bytes.as_mut().copy_from_slice(input);
+        Ok(Self(bytes))
+    }
+}
+
+impl<P: ParameterSet> AsRef<[u8]> for Ciphertext<P> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<P: ParameterSet> PartialEq for Ciphertext<P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::ciphertext::Ciphertext;
+    use crate::error::Error;
+    use crate::parameter_set::{McEliece6960119, ParameterSet};
+
+    #[test]
+    fn try_from_slice() {
+        type P = McEliece6960119;
+
+        let bytes = [0x2a; P::CIPHERTEXT_BYTES + 1];
+
+        let ciphertext = Ciphertext::<P>::try_from(&bytes[1..]).unwrap();
+        assert_eq!(ciphertext.as_ref(), &bytes[1..]);
+
+        assert_eq!(
+            Ciphertext::<P>::try_from(&bytes[..]).err(),
+            Some(Error::InvalidLength)
+        );
+    }
+}
@@ -1,115 +1,213 @@
+use core::marker::PhantomData;
+
+#[cfg(feature = "std")]
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "std")]
+use zeroize::Zeroizing;
+
+#[cfg(feature = "std")]
+use crate::ctgrind;
+#[cfg(feature = "std")]
 use crate::field_ordering::FieldOrdering;
+#[cfg(feature = "std")]
 use crate::generator::Generator;
+#[cfg(feature = "std")]
 use crate::monic_polynomial::MonicPolynomial;
+use crate::parameter_set::{ParameterSet, L, L_BYTES};
+#[cfg(feature = "std")]
+use crate::parameter_set::{MAX_N_BYTES, MAX_T, SIGMA_ONE_BYTES};
+#[cfg(feature = "std")]
 use crate::public_key::PublicKey;
+#[cfg(feature = "std")]
 use crate::secret_key::SecretKey;
 
-pub struct ClassicMcEliece {}
-
-impl ClassicMcEliece {
-    pub const M: usize = 12;
-    pub const N: usize = 3488;
-    pub const T: usize = 64;
-    pub const L: usize = 256;
-
-    pub(crate) const Q: usize = 1 << Self::M;
-    pub(crate) const K: usize = Self::N - Self::M * Self::T;
+/// Key generation, and the sizes of the parameter set `P`.
+pub struct ClassicMcEliece<P: ParameterSet>(PhantomData<P>);
 
-    pub(crate) const N_BYTES: usize = Self::N / 8;
-    pub(crate) const L_BYTES: usize = Self::L / 8;
-    pub(crate) const Q_BYTES: usize = Self::Q / 8;
-    pub(crate) const K_BYTES: usize = Self::K / 8;
+impl<P: ParameterSet> ClassicMcEliece<P> {
+    pub const M: usize = P::M;
+    pub const N: usize = P::N;
+    pub const T: usize = P::T;
+    pub const L: usize = L;
 
-    pub(crate) const SIGMA_ONE_BYTES: usize = 2;
-    pub(crate) const SIGMA_TWO_BYTES: usize = 4;
+    pub const CIPHERTEXT_BYTES: usize = P::CIPHERTEXT_BYTES;
 
-    pub(crate) const C0_BYTES: usize = (ClassicMcEliece::M * ClassicMcEliece::T) / 8;
-    pub(crate) const C1_BYTES: usize = ClassicMcEliece::L_BYTES;
-    pub const CIPHERTEXT_BYTES: usize = Self::C0_BYTES + Self::C1_BYTES;
-
-    pub const SESSION_KEY_BYTES: usize = ClassicMcEliece::L_BYTES;
+    pub const SESSION_KEY_BYTES: usize = L_BYTES;
 
+    /// Generates a keypair, drawing the seed from `rand::thread_rng`.
+    #[cfg(feature = "std")]
     #[inline]
-    pub fn generate_keypair() -> (SecretKey, PublicKey) {
-        use rand::RngCore;
+    pub fn generate_keypair() -> (SecretKey<P>, PublicKey<P>) {
+        Self::generate_keypair_with_rng(&mut rand::thread_rng())
+    }
 
-        let mut seed = [0u8; ClassicMcEliece::L_BYTES];
-        rand::thread_rng().fill_bytes(&mut seed);
+    /// Generates a keypair, drawing the seed from `rng`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn generate_keypair_with_rng<R: CryptoRng + RngCore>(
+        rng: &mut R,
+    ) -> (SecretKey<P>, PublicKey<P>) {
+        let mut seed = Zeroizing::new([0u8; L_BYTES]);
+        rng.fill_bytes(&mut *seed);
 
-        Self::generate_keypair_seeded(&seed)
+        Self::generate_keypair_from_seed(&seed)
     }
 
-    pub(crate) fn generate_keypair_seeded(
-        seed: &[u8; ClassicMcEliece::L_BYTES],
-    ) -> (SecretKey, PublicKey) {
-        let mut seed = *seed;
+    /// Deterministic: the same seed always gives the same keypair, and the seeds of the NIST KATs
+    /// give the keypairs of the KATs.
+    ///
+    /// Runs in constant time up to its decision to accept or retry an attempt: a singular matrix
+    /// or a duplicate in the field ordering only sets a flag.
+    #[cfg(feature = "std")]
+    pub fn generate_keypair_from_seed(seed: &[u8; L_BYTES]) -> (SecretKey<P>, PublicKey<P>) {
+        let mut seed = Zeroizing::new(*seed);
+        ctgrind::poison(&*seed);
 
         loop {
-            let mut s = [0u8; SecretKey::S_BYTES];
-            let mut alpha_seed = [0u8; Self::SIGMA_TWO_BYTES * Self::Q];
-            let mut g_seed = [0u8; Self::SIGMA_ONE_BYTES * Self::T];
+            let mut s = Zeroizing::new([0u8; MAX_N_BYTES]);
+            let mut alpha_seed = Zeroizing::new(vec![0u8; FieldOrdering::<P>::BYTES]);
+            let mut g_seed = Zeroizing::new([0u8; SIGMA_ONE_BYTES * MAX_T]);
+
+            let s = &mut s[..SecretKey::<P>::S_BYTES];
+            let g_seed = &mut g_seed[..MonicPolynomial::<P>::BYTES];
 
             let mut generator = Generator::new(64, &seed);
-            generator.squeeze(&mut s);
+            generator.squeeze(s);
             generator.squeeze(&mut alpha_seed);
-            generator.squeeze(&mut g_seed);
+            generator.squeeze(g_seed);
 
-            let alpha = match FieldOrdering::new(&alpha_seed) {
+            let mut alpha = match FieldOrdering::new(&alpha_seed) {
                 Some(alpha) => alpha,
                 None => {
-                    generator.squeeze(&mut seed);
+                    generator.squeeze(&mut *seed);
                     continue;
                 }
             };
 
-            let g = match MonicPolynomial::irreducible(&g_seed) {
-                Some(g) => g,
+            let g = match MonicPolynomial::irreducible(g_seed) {
+                Some(g) => Zeroizing::new(g),
                 None => {
-                    generator.squeeze(&mut seed);
+                    generator.squeeze(&mut *seed);
                     continue;
                 }
             };
 
-            let public_key = match PublicKey::generate(&g, &alpha) {
-                Some(public_key) => public_key,
+            let (public_key, pivots) = match PublicKey::generate(&g, &mut alpha) {
+                Some(generated) => generated,
                 None => {
-                    generator.squeeze(&mut seed);
+                    generator.squeeze(&mut *seed);
                     continue;
                 }
             };
 
-            return (SecretKey::new(&seed, &g, &alpha, &s), public_key);
+            let secret_key = SecretKey::new(&seed, pivots, &g, &alpha, s);
+
+            secret_key.unpoison();
+            ctgrind::unpoison(public_key.as_ref());
+
+            return (secret_key, public_key);
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::convert::TryFrom;
 
-    use crate::ClassicMcEliece;
+    use rand::{RngCore, SeedableRng};
 
-    #[test]
-    fn sanity_checks() {
-        assert!(ClassicMcEliece::N <= ClassicMcEliece::Q);
-        assert!(ClassicMcEliece::T >= 2);
-        assert!(ClassicMcEliece::M * ClassicMcEliece::T < ClassicMcEliece::N);
+    use crate::buffer::Array;
+    use crate::classic_mceliece::ClassicMcEliece;
+    use crate::parameter_set::*;
+    use crate::public_key::PublicKey;
+    use crate::secret_key::SecretKey;
+
+    fn sanity_checks<P: ParameterSet>() {
+        assert!(P::N <= P::Q);
+        assert!(P::T >= 2);
+        assert!(P::M * P::T < P::N);
 
-        assert!(ClassicMcEliece::Q.is_power_of_two());
+        assert!(P::Q.is_power_of_two());
 
-        assert!(ClassicMcEliece::SIGMA_ONE_BYTES * 8 >= ClassicMcEliece::M);
-        assert!(ClassicMcEliece::SIGMA_TWO_BYTES * 8 >= ClassicMcEliece::M * 2);
+        if P::SEMI_SYSTEMATIC {
+            assert!(P::M * P::T >= 32);
+            assert!(P::M * P::T + 64 <= P::N);
+        }
+
+        assert!(SIGMA_ONE_BYTES * 8 >= P::M);
+        assert!(SIGMA_TWO_BYTES * 8 >= P::M * 2);
 
         // Implementation-specific assumptions:
 
-        assert_eq!(ClassicMcEliece::N_BYTES * 8, ClassicMcEliece::N);
-        assert_eq!(ClassicMcEliece::L_BYTES * 8, ClassicMcEliece::L);
-        assert_eq!(ClassicMcEliece::K_BYTES * 8, ClassicMcEliece::K);
+        assert!(P::M <= MAX_M);
+        assert!(P::N <= MAX_N);
+        assert!(P::T <= MAX_T);
+        assert!(P::TAU <= 2 * MAX_T);
+
+        assert_eq!(P::N_BYTES * 8, P::N);
+        assert_eq!(L_BYTES * 8, L);
+
+        assert_eq!(
+            std::mem::size_of::<P::CiphertextBytes>(),
+            P::CIPHERTEXT_BYTES
+        );
+        assert_eq!(
+            std::mem::size_of::<P::SecretKeyBytes>(),
+            SecretKey::<P>::BYTES
+        );
+        assert_eq!(
+            std::mem::size_of::<P::PublicKeyBytes>(),
+            PublicKey::<P>::BYTES
+        );
+
+        assert_eq!(<P::QArray<u8> as Array<u8>>::LEN, P::Q);
+        assert_eq!(<P::DoubleQArray<u8> as Array<u8>>::LEN, 2 * P::Q);
+        assert_eq!(<P::NArray<u8> as Array<u8>>::LEN, P::N);
 
         // TODO: is_multiple_of, name
         //assert_eq!(ClassicMcEliece::PK_ROW_BYTES * 8, ClassicMcEliece::PK_ROWS);
 
-        assert!(u8::try_from(ClassicMcEliece::T).is_ok());
+        assert!(u8::try_from(P::T).is_ok());
         // TODO: More?
     }
+
+    #[test]
+    fn generate_keypair_with_rng() {
+        type P = McEliece348864;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair_with_rng(&mut rng);
+
+        let mut seed = [0u8; L_BYTES];
+        rand::rngs::StdRng::seed_from_u64(0).fill_bytes(&mut seed);
+        let (expected_secret_key, expected_public_key) =
+            ClassicMcEliece::<P>::generate_keypair_from_seed(&seed);
+
+        assert_eq!(secret_key.to_bytes(), expected_secret_key.to_bytes());
+        assert_eq!(public_key.to_bytes(), expected_public_key.to_bytes());
+    }
+
+    macro_rules! sanity_checks_tests {
+        ($($name:ident: $parameter_set:ty;)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    sanity_checks::<$parameter_set>();
+                }
+            )*
+        };
+    }
+
+    sanity_checks_tests! {
+        sanity_checks_mceliece348864: McEliece348864;
+        sanity_checks_mceliece460896: McEliece460896;
+        sanity_checks_mceliece6688128: McEliece6688128;
+        sanity_checks_mceliece6960119: McEliece6960119;
+        sanity_checks_mceliece8192128: McEliece8192128;
+        sanity_checks_mceliece348864f: McEliece348864f;
+        sanity_checks_mceliece460896f: McEliece460896f;
+        sanity_checks_mceliece6688128f: McEliece6688128f;
+        sanity_checks_mceliece6960119f: McEliece6960119f;
+        sanity_checks_mceliece8192128f: McEliece8192128f;
+    }
 }
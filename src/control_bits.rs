@@ -1,929 +1,532 @@
-use std::convert::TryInto;
+use core::marker::PhantomData;
 
-use crate::classic_mceliece::ClassicMcEliece;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::backend;
+use crate::buffer::{Buffer, DoubleQBuffer, NBuffer, QBuffer};
 use crate::field_element::FieldElement;
+use crate::parameter_set::{ParameterSet, MAX_Q};
 
+// The (2M - 1)Q/16 bytes of the control bits fit in 2Q.
 #[derive(Debug)]
-pub(crate) struct ControlBits([u8; Self::BYTES]);
+pub(crate) struct ControlBits<P: ParameterSet>(DoubleQBuffer<P, u8>, PhantomData<P>);
+
+impl<P: ParameterSet> ControlBits<P> {
+    pub(crate) const BYTES: usize = (2 * P::M - 1) * (1 << (P::M - 4));
 
-impl ControlBits {
-    pub(crate) const BYTES: usize = (2 * ClassicMcEliece::M - 1) * (1 << (ClassicMcEliece::M - 4));
+    const LAYER_BYTES: usize = P::Q / 16;
 
     #[inline]
-    pub(crate) fn from_bytes(bytes: &[u8; Self::BYTES]) -> Self {
-        Self(*bytes)
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), Self::BYTES);
+
+        let mut control_bits = Self(Buffer::new(0, Self::BYTES), PhantomData);
+        control_bits.0.copy_from_slice(bytes);
+        control_bits
     }
 
-    pub(crate) fn generate_support(&self) -> [FieldElement; ClassicMcEliece::N] {
-        let mut matrix = [[0u8; ClassicMcEliece::Q_BYTES]; ClassicMcEliece::M];
+    // Computes the control bits of the Benes network for the permutation, following Bernstein's
+    // "Verified fast formulas for control bits for permutation networks".
+    pub(crate) fn from_permutation(permutation: &[FieldElement<P>]) -> Self {
+        debug_assert_eq!(permutation.len(), P::Q);
 
-        for i in 0..ClassicMcEliece::Q {
-            let a = FieldElement::from(i as u16).reverse_bits();
-            for (j, row) in matrix.iter_mut().enumerate() {
-                row[i / 8] |= (a.get_bit(j) << (i % 8)) as u8;
-            }
+        let mut pi = QBuffer::<P, i16>::new(0, P::Q);
+        for (pi, alpha) in pi.iter_mut().zip(permutation.iter()) {
+            *pi = u16::from(*alpha) as i16;
         }
 
-        for row in matrix.iter_mut() {
-            self.apply_benes(row)
-        }
+        let mut control_bits = Self(Buffer::new(0, Self::BYTES), PhantomData);
 
-        let mut support = [FieldElement::ZERO; ClassicMcEliece::N];
+        let mut temp = DoubleQBuffer::<P, i32>::new(0, 2 * P::Q);
+        let mut scratch = DoubleQBuffer::<P, i16>::new(0, 2 * P::Q);
 
-        for (i, limb) in support.iter_mut().enumerate() {
-            *limb = FieldElement::from(
-                matrix
-                    .iter()
-                    .rev()
-                    .map(|row| ((row[i / 8] >> (i % 8)) & 0b1) as u16)
-                    .fold(0, |c, bit| (c << 1) | bit),
-            );
-        }
+        cb_recursion(
+            &mut control_bits.0,
+            0,
+            1,
+            &pi,
+            P::M,
+            &mut temp,
+            &mut scratch,
+        );
+
+        debug_assert!(bool::from(control_bits.permutation().ct_eq(permutation)));
+
+        control_bits
+    }
 
+    #[inline]
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    // Checks that these are the control bits computed for the permutation they encode, which holds
+    // for any key produced by key generation.
+    pub(crate) fn is_canonical(&self) -> bool {
+        ControlBits::<P>::from_permutation(&self.permutation())
+            .as_bytes()
+            .ct_eq(self.as_bytes())
+            .into()
+    }
+
+    #[cfg(all(any(test, feature = "fuzzing"), feature = "std"))]
+    pub(crate) fn generate_support(&self) -> NBuffer<P, FieldElement<P>> {
+        let mut support = Buffer::new(FieldElement::ZERO, P::N);
+        self.permute(
+            |i| FieldElement::<P>::from(i as u16).reverse_bits(),
+            false,
+            &mut support,
+        );
         support
     }
 
-    fn apply_benes(&self, bits: &mut [u8; 8 * 64]) {
-        #[inline]
-        fn load_u64(input: &[u8], output: &mut [u64; 64]) {
-            for (output, bytes) in output.iter_mut().zip(input.chunks_exact(8)) {
-                *output = u64::from_le_bytes(bytes.try_into().unwrap());
+    // Takes the output of `fft` to the order of the support: returns values[π(i)] for i < N.
+    pub(crate) fn to_support_order(
+        &self,
+        values: &[FieldElement<P>],
+    ) -> NBuffer<P, FieldElement<P>> {
+        debug_assert_eq!(values.len(), P::Q);
+
+        let mut output = Buffer::new(FieldElement::ZERO, P::N);
+        self.permute(|i| values[i], false, &mut output);
+        output
+    }
+
+    // The inverse of `to_support_order`, for the input of `fft_tr`; the other Q - N elements are
+    // zero.
+    pub(crate) fn to_fft_order(&self, values: &[FieldElement<P>]) -> QBuffer<P, FieldElement<P>> {
+        debug_assert_eq!(values.len(), P::N);
+
+        let mut output = Buffer::new(FieldElement::ZERO, P::Q);
+        self.permute(
+            |i| values.get(i).copied().unwrap_or(FieldElement::ZERO),
+            true,
+            &mut output,
+        );
+        output
+    }
+
+    fn permutation(&self) -> QBuffer<P, FieldElement<P>> {
+        let mut permutation = Buffer::new(FieldElement::ZERO, P::Q);
+        self.permute(
+            |i| FieldElement::<P>::from(i as u16),
+            false,
+            &mut permutation,
+        );
+        permutation
+    }
+
+    // Applies the Benes network, or its inverse, to the sequence (f(0), ..., f(Q - 1)) and writes
+    // the first output.len() elements of the result to `output`, which must be zero. Goes through
+    // the M bit planes one at a time, so that only a single row of Q bits is held at once.
+    fn permute(
+        &self,
+        f: impl Fn(usize) -> FieldElement<P>,
+        inverse: bool,
+        output: &mut [FieldElement<P>],
+    ) {
+        debug_assert!(output.len() <= P::Q);
+
+        let mut row = Zeroizing::new([0u64; MAX_Q / 64]);
+        let row = &mut row[..(P::Q / 64)];
+
+        for j in 0..P::M {
+            row.fill(0);
+            for i in 0..P::Q {
+                row[i / 64] |= (f(i).get_bit(j) as u64) << (i % 64);
             }
-        }
 
-        #[inline]
-        fn load_u32(input: &[u8], output: &mut [u64; 64]) {
-            for (output, bytes) in output.iter_mut().zip(input.chunks_exact(4)) {
-                *output = u32::from_le_bytes(bytes.try_into().unwrap()) as u64;
+            self.apply_benes(row, inverse);
+
+            for (i, output) in output.iter_mut().enumerate() {
+                let bit = ((row[i / 64] >> (i % 64)) & 0b1) as u16;
+                *output = FieldElement::from(u16::from(*output) | (bit << j));
             }
         }
+    }
 
-        let mut matrix = [0u64; 64];
-        load_u64(bits, &mut matrix);
+    // The layer sizes are symmetric, so taking the layers in reverse order inverts the network.
+    fn apply_benes(&self, bits: &mut [u64], inverse: bool) {
+        debug_assert_eq!(bits.len() * 64, P::Q);
 
-        let mut cond = [0u64; 64];
-        let mut condition_bits = self.0.chunks_exact(256);
+        let layers = self.as_bytes().chunks_exact(Self::LAYER_BYTES);
+        debug_assert_eq!(layers.len(), 2 * P::M - 1);
 
-        transpose(&mut matrix);
+        let lgs = (0..P::M).chain((0..(P::M - 1)).rev());
 
-        for low in 0..=5 {
-            load_u32(condition_bits.next().unwrap(), &mut cond);
-            transpose(&mut cond);
-            layer(&mut matrix, &cond, low);
+        if inverse {
+            for (condition_bits, lgs) in layers.rev().zip(lgs) {
+                layer(bits, condition_bits, lgs);
+            }
+        } else {
+            for (condition_bits, lgs) in layers.zip(lgs) {
+                layer(bits, condition_bits, lgs);
+            }
         }
+    }
+}
+
+// Performs the conditional swaps of positions (i + j, i + j + 2^lgs), consuming one condition bit
+// per swap, for every block i of 2^(lgs + 1) positions.
+fn layer(data: &mut [u64], condition_bits: &[u8], lgs: usize) {
+    let s = 1 << lgs;
 
-        transpose(&mut matrix);
+    if s >= 64 {
+        let s = s / 64;
 
-        for low in 0..=5 {
-            load_u64(condition_bits.next().unwrap(), &mut cond);
-            layer(&mut matrix, &cond, low);
+        for (block, condition_bits) in data
+            .chunks_exact_mut(2 * s)
+            .zip(condition_bits.chunks_exact(8 * s))
+        {
+            let (lhs, rhs) = block.split_at_mut(s);
+            backend::conditional_swap(lhs, rhs, condition_bits);
         }
+    } else {
+        backend::conditional_swap_within(data, condition_bits, s);
+    }
+}
 
-        for low in (0..=4).rev() {
-            load_u64(condition_bits.next().unwrap(), &mut cond);
-            layer(&mut matrix, &cond, low);
+// Writes the (2w - 1)n/2 control bits for the permutation `pi` of {0, 1, ..., n - 1}, n = 2^w, at
+// bit positions pos, pos + step, ...; `temp` must hold 2n and `scratch` 2n elements.
+fn cb_recursion(
+    out: &mut [u8],
+    mut pos: usize,
+    step: usize,
+    pi: &[i16],
+    w: usize,
+    temp: &mut [i32],
+    scratch: &mut [i16],
+) {
+    let n = pi.len();
+    debug_assert_eq!(n, 1 << w);
+
+    if w == 1 {
+        out[pos >> 3] ^= (pi[0] as u8) << (pos & 7);
+        return;
+    }
+
+    let (a, b) = temp[..(2 * n)].split_at_mut(n);
+
+    for (x, a) in a.iter_mut().enumerate() {
+        *a = (((pi[x] ^ 1) as i32) << 16) | pi[x ^ 1] as i32;
+    }
+    int32_sort(a); // A = (id << 16) + pibar
+
+    for (x, (b, a)) in b.iter_mut().zip(a.iter()).enumerate() {
+        let px = a & 0xffff;
+        *b = (px << 16) | int32_min(px, x as i32);
+    }
+    // B = (p << 16) + c
+
+    for (x, a) in a.iter_mut().enumerate() {
+        *a = (*a << 16) | x as i32; // A = (pibar << 16) + id
+    }
+    int32_sort(a); // A = (id << 16) + pibar^-1
+
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a = (*a << 16) + (b >> 16); // A = (pibar^-1 << 16) + pibar
+    }
+    int32_sort(a); // A = (id << 16) + pibar^2
+
+    if w <= 10 {
+        for (b, a) in b.iter_mut().zip(a.iter()) {
+            *b = ((a & 0xffff) << 10) | (*b & 0x3ff);
         }
 
-        transpose(&mut matrix);
+        for _ in 1..(w - 1) {
+            // B = (p << 10) + c
+
+            for (x, (a, b)) in a.iter_mut().zip(b.iter()).enumerate() {
+                *a = ((b & !0x3ff) << 6) | x as i32; // A = (p << 16) + id
+            }
+            int32_sort(a); // A = (id << 16) + p^-1
+
+            for (a, b) in a.iter_mut().zip(b.iter()) {
+                *a = (*a << 20) | b; // A = (p^-1 << 20) + (p << 10) + c
+            }
+            int32_sort(a); // A = (id << 20) + (pp << 10) + cp
+
+            for (b, a) in b.iter_mut().zip(a.iter()) {
+                let ppcpx = a & 0xfffff;
+                let ppcx = (a & 0xffc00) | (*b & 0x3ff);
+                *b = int32_min(ppcx, ppcpx);
+            }
+        }
 
-        for low in (0..=5).rev() {
-            load_u32(condition_bits.next().unwrap(), &mut cond);
-            transpose(&mut cond);
-            layer(&mut matrix, &cond, low);
+        for b in b.iter_mut() {
+            *b &= 0x3ff;
+        }
+    } else {
+        for (b, a) in b.iter_mut().zip(a.iter()) {
+            *b = (a << 16) | (*b & 0xffff);
         }
 
-        transpose(&mut matrix);
+        for i in 1..(w - 1) {
+            // B = (p << 16) + c
+
+            for (x, (a, b)) in a.iter_mut().zip(b.iter()).enumerate() {
+                *a = (b & !0xffff) | x as i32;
+            }
+            int32_sort(a); // A = (id << 16) + p^-1
+
+            for (a, b) in a.iter_mut().zip(b.iter()) {
+                *a = (*a << 16) | (b & 0xffff); // A = (p^-1 << 16) + c
+            }
+
+            if i < w - 2 {
+                for (b, a) in b.iter_mut().zip(a.iter()) {
+                    *b = (a & !0xffff) | (*b >> 16); // B = (p^-1 << 16) + p
+                }
+                int32_sort(b); // B = (id << 16) + p^-2
+
+                for (b, a) in b.iter_mut().zip(a.iter()) {
+                    *b = (*b << 16) | (a & 0xffff); // B = (p^-2 << 16) + c
+                }
+            }
+
+            int32_sort(a); // A = (id << 16) + cp
+
+            for (b, a) in b.iter_mut().zip(a.iter()) {
+                let cpx = (*b & !0xffff) | (a & 0xffff);
+                *b = int32_min(cpx, *b);
+            }
+        }
 
-        for (bytes, row) in bits.chunks_exact_mut(8).zip(matrix.iter()) {
-            bytes.copy_from_slice(&row.to_le_bytes());
+        for b in b.iter_mut() {
+            *b &= 0xffff;
         }
+    }
 
-        debug_assert!(condition_bits.next().is_none());
+    for (x, a) in a.iter_mut().enumerate() {
+        *a = ((pi[x] as i32) << 16) + x as i32;
     }
+    int32_sort(a); // A = (id << 16) + pi^-1
+
+    for (x, (a, b)) in a.chunks_exact(2).zip(b.chunks_exact_mut(2)).enumerate() {
+        let fj = b[0] & 1; // f[j]
+        let fx = 2 * x as i32 + fj; // F[x]
+        let fx1 = fx ^ 1; // F[x + 1]
+
+        out[pos >> 3] ^= (fj as u8) << (pos & 7);
+        pos += step;
+
+        b[0] = (a[0] << 16) | fx;
+        b[1] = (a[1] << 16) | fx1;
+    }
+    // B = (pi^-1 << 16) + F
+
+    int32_sort(b); // B = (id << 16) + F(pi)
+
+    pos += (2 * w - 3) * step * (n / 2);
+
+    for (y, (a, b)) in a.chunks_exact_mut(2).zip(b.chunks_exact(2)).enumerate() {
+        let lk = b[0] & 1; // l[k]
+        let ly = 2 * y as i32 + lk; // L[y]
+        let ly1 = ly ^ 1; // L[y + 1]
+
+        out[pos >> 3] ^= (lk as u8) << (pos & 7);
+        pos += step;
+
+        a[0] = (ly << 16) | (b[0] & 0xffff);
+        a[1] = (ly1 << 16) | (b[1] & 0xffff);
+    }
+    // A = (L << 16) + F(pi)
+
+    int32_sort(a); // A = (id << 16) + F(pi(L)) = (id << 16) + M
+
+    pos -= (2 * w - 2) * step * (n / 2);
+
+    let (q, scratch) = scratch.split_at_mut(n);
+    let (q0, q1) = q.split_at_mut(n / 2);
+
+    for ((q0, q1), a) in q0.iter_mut().zip(q1.iter_mut()).zip(a.chunks_exact(2)) {
+        *q0 = ((a[0] & 0xffff) >> 1) as i16;
+        *q1 = ((a[1] & 0xffff) >> 1) as i16;
+    }
+
+    cb_recursion(out, pos, step * 2, q0, w - 1, temp, scratch);
+    cb_recursion(out, pos + step, step * 2, q1, w - 1, temp, scratch);
 }
 
-fn transpose(matrix: &mut [u64; 64]) {
-    const MASKS: [(u64, u64); 6] = [
-        (0x5555555555555555, 0xaaaaaaaaaaaaaaaa),
-        (0x3333333333333333, 0xcccccccccccccccc),
-        (0x0f0f0f0f0f0f0f0f, 0xf0f0f0f0f0f0f0f0),
-        (0x00ff00ff00ff00ff, 0xff00ff00ff00ff00),
-        (0x0000ffff0000ffff, 0xffff0000ffff0000),
-        (0x00000000ffffffff, 0xffffffff00000000),
-    ];
-
-    for (d, masks) in MASKS.iter().enumerate().rev() {
-        let s = 1 << d;
-
-        for i in (0..64).step_by(2 * s) {
-            for j in i..(i + s) {
-                let x = (matrix[j] & masks.0) | ((matrix[j + s] & masks.0) << s);
-                let y = ((matrix[j] & masks.1) >> s) | (matrix[j + s] & masks.1);
-                matrix[j + 0] = x;
-                matrix[j + s] = y;
+// Sorts in constant time with Batcher's odd-even merge sort, as in djbsort.
+fn int32_sort(x: &mut [i32]) {
+    let n = x.len();
+
+    if n < 2 {
+        return;
+    }
+
+    let mut top = 1;
+    while top < n - top {
+        top += top;
+    }
+
+    let mut p = top;
+    while p > 0 {
+        for i in (0..(n - p)).filter(|i| i & p == 0) {
+            let (lo, hi) = x.split_at_mut(i + p);
+            int32_minmax(&mut lo[i], &mut hi[0]);
+        }
+
+        let mut i = 0;
+        let mut q = top;
+        while q > p {
+            while i < n - q {
+                if i & p == 0 {
+                    let mut a = x[i + p];
+                    let mut r = q;
+                    while r > p {
+                        int32_minmax(&mut a, &mut x[i + r]);
+                        r >>= 1;
+                    }
+                    x[i + p] = a;
+                }
+                i += 1;
             }
+            q >>= 1;
         }
+
+        p >>= 1;
     }
 }
 
-fn layer(data: &mut [u64; 64], selected_bits: &[u64; 64], lgs: usize) {
-    let mut selected_bits = selected_bits.iter();
+fn int32_minmax(a: &mut i32, b: &mut i32) {
+    let ab = *b ^ *a;
+    let mut c = b.wrapping_sub(*a);
+    c ^= ab & (c ^ *b);
+    c >>= 31;
+    c &= ab;
+    *a ^= c;
+    *b ^= c;
+}
 
-    let s = 1 << lgs;
+fn int32_min(mut a: i32, mut b: i32) -> i32 {
+    int32_minmax(&mut a, &mut b);
+    a
+}
 
-    for i in (0..64).step_by(2 * s) {
-        for j in i..(i + s) {
-            let swap_operator = (data[j + 0] ^ data[j + s]) & selected_bits.next().unwrap();
-            data[j + 0] ^= swap_operator;
-            data[j + s] ^= swap_operator;
-        }
+// Wipes the bits in place; the buffer itself is also zeroized when it is dropped.
+impl<P: ParameterSet> Zeroize for ControlBits<P> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0[..].zeroize();
     }
 }
 
 #[cfg(test)]
-impl PartialEq<ControlBits> for ControlBits {
-    fn eq(&self, other: &ControlBits) -> bool {
-        self.0 == other.0
+impl<P: ParameterSet> PartialEq<ControlBits<P>> for ControlBits<P> {
+    fn eq(&self, other: &ControlBits<P>) -> bool {
+        self.as_bytes() == other.as_bytes()
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     #[test]
-//     fn test_apply_benes() {
-//         use super::apply_benes;
-//         use rand::Rng;
-//         use std::convert::TryInto;
-
-//         let mut expected = [0u8; 8 * 64];
-//         rand::thread_rng().fill(&mut expected[..]);
-
-//         let mut matrix = expected;
-
-//         let mut condition_bits = [0u8; 5888];
-//         rand::thread_rng().fill(&mut condition_bits[..]);
-
-//         apply_benes(&mut matrix, &condition_bits);
-
-//         let reversed_condition_bits: Vec<u8> = condition_bits
-//             .rchunks_exact(256)
-//             .flatten()
-//             .copied()
-//             .collect();
-
-//         apply_benes(&mut matrix, &reversed_condition_bits.try_into().unwrap());
-
-//         assert_eq!(matrix, expected);
-//     }
-
-//     #[test]
-//     fn test_transpose() {
-//         use super::transpose;
-//         use rand::Rng;
-
-//         let mut expected = [0u64; 64];
-//         rand::thread_rng().fill(&mut expected[..]);
-
-//         let mut matrix = expected;
-
-//         transpose(&mut matrix);
-//         transpose(&mut matrix);
-
-//         assert_eq!(matrix, expected);
-//     }
-// }
-
-// #[cfg(test)]
-// mod tests {
-//     #[test]
-//     fn it_works() {
-//         const M: usize = 12;
-//         const Q: usize = 1 << M;
-
-//         assert_eq!(PERMUTATION.len(), Q);
-//         assert_eq!(EXPECTED.len(), (2 * M - 1) * (1 << (M - 4)));
-
-//         let mut control_bits = super::ControlBits::new()
-//         super::control_bits_from_permutation(
-//             unsafe { std::mem::transmute(PERMUTATION) }, // TODO: unsafe.
-//             &mut output,
-//         );
-
-//         assert_eq!(output, EXPECTED);
-//     }
-
-//     const PERMUTATION: [u16] = [
-//         0x501, 0x987, 0x00a, 0x8fd, 0x173, 0x14a, 0xf6e, 0xa40, 0x1f3, 0x4cf, 0xe94, 0x856, 0x75a,
-//         0x1d4, 0x01a, 0xa41, 0x6fd, 0xd8b, 0x1e6, 0x9b9, 0xf61, 0xe16, 0xc00, 0x481, 0x385, 0x103,
-//         0x2be, 0xb58, 0xe0b, 0x2e5, 0x969, 0xf7f, 0xd9a, 0xce2, 0x421, 0x119, 0x0d9, 0x975, 0xd47,
-//         0x0f1, 0x831, 0x92b, 0x848, 0x3be, 0xdff, 0x770, 0x627, 0x431, 0x965, 0x982, 0x22d, 0x0df,
-//         0x0ad, 0x720, 0x6b6, 0x5a3, 0x4af, 0xf2d, 0x23d, 0x30a, 0x48c, 0xbf4, 0x016, 0x9b7, 0x933,
-//         0x5ad, 0xcfd, 0x4e5, 0xeac, 0x4d6, 0xa03, 0xf86, 0x6e4, 0x151, 0xdc2, 0x2d9, 0x134, 0xc3a,
-//         0x88f, 0x425, 0x004, 0x78d, 0x92a, 0x383, 0xdf4, 0xa4d, 0x293, 0x9f8, 0x339, 0x3f6, 0x2ff,
-//         0x86d, 0x758, 0x958, 0x457, 0x8de, 0xe65, 0xd45, 0xaac, 0x583, 0xfb1, 0xa94, 0x379, 0x921,
-//         0x9c9, 0x4c0, 0x2e6, 0x8b5, 0x727, 0x7b3, 0x6de, 0x682, 0xccb, 0xc3f, 0x0ec, 0x0f5, 0xf79,
-//         0xbe3, 0x57f, 0x493, 0x3e9, 0x563, 0xe1d, 0x0fc, 0xadc, 0x0b5, 0xdf3, 0x160, 0x4a1, 0xba0,
-//         0xa31, 0x60e, 0xc65, 0x854, 0x5bd, 0xbcd, 0x216, 0x50d, 0xe17, 0xf6f, 0x25e, 0x0e1, 0x02b,
-//         0x46c, 0x741, 0x80d, 0xdbc, 0x20c, 0x36c, 0x0d6, 0xceb, 0x299, 0xe10, 0x23c, 0x205, 0x2b4,
-//         0x9d2, 0x5dc, 0xfa1, 0xc9d, 0xb92, 0xc2b, 0x14c, 0x7fc, 0x128, 0x798, 0xca4, 0x72d, 0xae7,
-//         0xd4c, 0x5b6, 0xcd5, 0x2fc, 0x359, 0xe0a, 0xeeb, 0x0f3, 0x085, 0x20f, 0x2fa, 0x40d, 0x13b,
-//         0x0e4, 0x4b5, 0x63a, 0xf31, 0x343, 0x76a, 0x3ae, 0x07e, 0x42f, 0x746, 0xc87, 0xd76, 0x3f9,
-//         0x9f9, 0x046, 0xa57, 0x188, 0xab3, 0x713, 0xcf4, 0x504, 0x756, 0xee8, 0x941, 0x274, 0xc25,
-//         0x124, 0x0b3, 0x8f2, 0x193, 0xe29, 0x7f6, 0xd35, 0xf67, 0x084, 0xeff, 0xaea, 0x6b2, 0x06c,
-//         0x6c6, 0xcec, 0x37b, 0xc62, 0x2bf, 0xc30, 0xaaf, 0x58a, 0xc14, 0xb78, 0x26a, 0x5d7, 0x145,
-//         0x0b0, 0xac6, 0x625, 0xfca, 0x8a2, 0x890, 0x3c1, 0x861, 0x836, 0x75e, 0xc4b, 0xb70, 0xe53,
-//         0xabe, 0x55e, 0xbf6, 0x137, 0x2ad, 0x3f7, 0x68d, 0x9d0, 0xf18, 0x1b0, 0xf25, 0x055, 0xea3,
-//         0x220, 0x65e, 0x8b3, 0x489, 0xe02, 0xa15, 0xf51, 0x1c9, 0x05e, 0x51e, 0x6b1, 0x5e9, 0x7c1,
-//         0x89e, 0xadd, 0x2ac, 0xd8f, 0xa39, 0x126, 0x16d, 0x9d5, 0xcd3, 0x5a9, 0x1ae, 0xa16, 0x2cf,
-//         0xe07, 0xbcb, 0x3cd, 0xb1a, 0x940, 0x8a1, 0x751, 0x9bb, 0x04c, 0x00b, 0x2a0, 0x923, 0x942,
-//         0x99e, 0x020, 0x3ff, 0xdee, 0x9b3, 0x77b, 0xb6d, 0x55c, 0xaf9, 0x4a8, 0x7c3, 0xd4b, 0x7eb,
-//         0xdf1, 0x68a, 0xaa0, 0xd7b, 0xcb2, 0xb2d, 0x0c5, 0x5cd, 0xeb2, 0x426, 0x768, 0xaca, 0xab6,
-//         0x21e, 0x7aa, 0x545, 0x1e1, 0xd95, 0xd09, 0xa3f, 0xcf1, 0xd61, 0x584, 0x564, 0x0be, 0x246,
-//         0x8be, 0x24a, 0xc6e, 0x2e0, 0x5ce, 0xf0d, 0x182, 0xc09, 0x534, 0xc18, 0x310, 0x3a0, 0xfde,
-//         0x276, 0xec3, 0xbc5, 0x157, 0xc27, 0x7c7, 0x879, 0xcf6, 0xeea, 0x3a7, 0xc1a, 0x2f3, 0x24e,
-//         0x663, 0xfb2, 0x6f4, 0x510, 0x872, 0x531, 0x3da, 0xeb5, 0x86b, 0x901, 0x499, 0x690, 0xdc0,
-//         0x646, 0x0a3, 0xedf, 0x867, 0xf4f, 0x4bb, 0x33e, 0xc13, 0x838, 0xd63, 0x185, 0x703, 0x108,
-//         0x802, 0xa26, 0xe30, 0x6ab, 0x86f, 0x1d0, 0x79a, 0x23f, 0x0d8, 0xd6d, 0x34b, 0xbb1, 0x8ef,
-//         0x7db, 0x272, 0x8bd, 0x2ef, 0x67e, 0x290, 0xbc9, 0x1e5, 0x27a, 0xf4c, 0x53e, 0x5b0, 0x660,
-//         0xfa5, 0xba5, 0xa04, 0x43f, 0xd12, 0x82d, 0x3df, 0x174, 0x101, 0x156, 0x334, 0xf3e, 0x738,
-//         0x520, 0x0e5, 0x69d, 0x607, 0x0ed, 0x8bf, 0xa0d, 0xe90, 0x9e1, 0xf23, 0x796, 0x2c4, 0x0cd,
-//         0xb07, 0xfb5, 0x87d, 0xa08, 0x6f3, 0xdd9, 0xe4e, 0x93a, 0xe73, 0x653, 0x48e, 0xe7b, 0xd39,
-//         0x53c, 0xf49, 0x105, 0x2d7, 0xff4, 0x53b, 0x83a, 0x152, 0x218, 0xc05, 0xe3d, 0x3a2, 0x3b5,
-//         0x66f, 0xfef, 0xc94, 0x502, 0xb74, 0xd6a, 0x599, 0xc29, 0x506, 0x13f, 0x1d2, 0xc7d, 0x64d,
-//         0x2da, 0x7d3, 0xf71, 0xd2e, 0x0e6, 0x1bc, 0xed4, 0xddd, 0x9ed, 0x3b6, 0x010, 0x4a5, 0x633,
-//         0xecd, 0x69a, 0xb60, 0xe60, 0xd23, 0xcfc, 0x8f5, 0x362, 0x90d, 0xc08, 0x0f7, 0x40e, 0x546,
-//         0xe8a, 0x4db, 0xf0b, 0xc97, 0x2f1, 0xbf1, 0xf69, 0x96c, 0x78a, 0x984, 0x977, 0x3aa, 0x46d,
-//         0x023, 0xedb, 0xb23, 0x73b, 0x509, 0x250, 0xfba, 0xa5d, 0x537, 0xbb7, 0xcda, 0xe21, 0x2f0,
-//         0x67a, 0x45b, 0x34c, 0xb40, 0x895, 0x08d, 0xba6, 0x0ac, 0x42a, 0xc84, 0x0ef, 0x5f7, 0x6ae,
-//         0x14e, 0x296, 0xcf0, 0x33a, 0x2d5, 0x192, 0xc0b, 0xdc5, 0xa00, 0x9e8, 0xa22, 0xffb, 0xf4d,
-//         0xf28, 0x18a, 0xed7, 0x0cf, 0x347, 0xd8d, 0xc42, 0x874, 0x96b, 0x605, 0xbca, 0x482, 0x121,
-//         0x7b1, 0x9cf, 0xebe, 0x669, 0x140, 0xf6b, 0xd9b, 0x435, 0xdfe, 0x158, 0x3f0, 0x15e, 0xaa7,
-//         0x880, 0x76b, 0xeb0, 0xc51, 0x16a, 0x8d4, 0x732, 0x5aa, 0x31e, 0x787, 0xea5, 0x11e, 0x0a8,
-//         0x702, 0x559, 0xd88, 0xc88, 0xa2b, 0x897, 0xac5, 0xe85, 0x947, 0xd19, 0xe76, 0x89f, 0x0d5,
-//         0x8b0, 0x202, 0xa0a, 0x33f, 0x7e5, 0xa9b, 0x30f, 0x1d8, 0xfc0, 0xa14, 0xac4, 0xe11, 0x29b,
-//         0x4ac, 0x6dd, 0xc53, 0x0d4, 0xaef, 0x840, 0x178, 0xa5f, 0x725, 0xafc, 0x1d9, 0x90a, 0xa83,
-//         0x81c, 0x32e, 0x6bd, 0xf42, 0xebd, 0x27c, 0x1c5, 0xb24, 0x902, 0x667, 0x6c9, 0x467, 0x961,
-//         0x4d1, 0x1d3, 0x281, 0xbb9, 0x7a4, 0x09d, 0xa23, 0xf96, 0xefa, 0xa4c, 0x063, 0x19d, 0xa11,
-//         0xce6, 0xe81, 0xf0f, 0x635, 0xe9a, 0x0a4, 0x5a6, 0xb63, 0xb32, 0x541, 0x54e, 0xe14, 0xde7,
-//         0x862, 0x4ce, 0x776, 0xc90, 0x60c, 0x782, 0xa48, 0x4d9, 0x0f4, 0x083, 0x215, 0xcce, 0x256,
-//         0xd07, 0x028, 0xfa8, 0x6c8, 0x5f9, 0x716, 0xfea, 0x9cd, 0xa49, 0x670, 0xb04, 0x8d7, 0x483,
-//         0xc43, 0x090, 0xc7a, 0xa24, 0x60a, 0x5d9, 0x793, 0xb8e, 0x6c4, 0x0a1, 0x056, 0xaf4, 0x11a,
-//         0x9a7, 0x41a, 0x4ca, 0x2a8, 0x38b, 0x40a, 0xd41, 0x2a6, 0x730, 0xa5a, 0x6cb, 0xffc, 0xc9e,
-//         0x217, 0xc0e, 0xc9f, 0x1bd, 0xc1e, 0x810, 0xfff, 0xc6c, 0x075, 0x780, 0xa9e, 0xabf, 0x855,
-//         0x7e1, 0xc9b, 0x1f4, 0xbfa, 0xb22, 0xa70, 0x73f, 0x66b, 0x4ea, 0x7ca, 0x315, 0x38e, 0xee7,
-//         0xf1e, 0x9a0, 0x90b, 0x3bd, 0x516, 0x6ba, 0xf94, 0x42e, 0x4b6, 0xe58, 0xa28, 0x68e, 0x822,
-//         0x2b1, 0x6f6, 0x3f2, 0x8e0, 0x98d, 0x86a, 0xb56, 0xa3a, 0x071, 0x064, 0x266, 0x871, 0xbe5,
-//         0x8b2, 0xb66, 0xefe, 0xd58, 0xaeb, 0x446, 0xefd, 0x356, 0x0a5, 0x6b7, 0x64e, 0xe72, 0xb3a,
-//         0x85b, 0xfe2, 0x02d, 0x96d, 0x971, 0xe8e, 0xf16, 0xa1c, 0x708, 0x44a, 0xb59, 0xdaa, 0xda1,
-//         0x01f, 0xf5a, 0x10b, 0x9be, 0x045, 0xf7b, 0x3c2, 0x7b7, 0x325, 0x5f1, 0x181, 0x8fb, 0xe82,
-//         0xa82, 0x99f, 0xc3b, 0xf78, 0x9a4, 0xd08, 0x681, 0xb8d, 0xfaf, 0xd3e, 0x889, 0xcc7, 0x790,
-//         0x72b, 0x90c, 0xa99, 0x5c5, 0x949, 0x8dd, 0x43b, 0xb10, 0x048, 0x22f, 0x16e, 0x955, 0x338,
-//         0xf87, 0xb9a, 0xd22, 0xd74, 0x5f3, 0xc24, 0x718, 0x795, 0xff8, 0xcd8, 0x443, 0x3f4, 0xafe,
-//         0x057, 0x9a5, 0xde4, 0x53a, 0x72e, 0x884, 0x46f, 0xd52, 0x6ed, 0x2ea, 0x666, 0xf95, 0xfd7,
-//         0x658, 0x56a, 0x2eb, 0x686, 0x835, 0x5ed, 0x61e, 0x4d5, 0x74f, 0x1eb, 0xecf, 0x0eb, 0xc3e,
-//         0x536, 0xf7e, 0x318, 0x60f, 0x034, 0x311, 0x406, 0x6c7, 0x2f9, 0xce1, 0xb84, 0x407, 0x022,
-//         0x20b, 0x8ff, 0xb81, 0xbf0, 0x972, 0xad0, 0x9ef, 0x9ad, 0x19c, 0x6d5, 0x210, 0xde9, 0x55f,
-//         0x4a4, 0xeaa, 0x05f, 0xdba, 0xcdb, 0xfb8, 0x0d7, 0xede, 0x4d3, 0xe66, 0x08a, 0x1f8, 0x0bd,
-//         0x7c2, 0x9ab, 0xb8b, 0xb6e, 0xc1b, 0xdd7, 0x0e7, 0xf00, 0x9e7, 0x1b6, 0xb02, 0xb27, 0x729,
-//         0x654, 0x340, 0xfd5, 0x3d7, 0x54a, 0x28e, 0xf19, 0x90e, 0x5ee, 0xd50, 0x450, 0x306, 0xd6c,
-//         0x7ce, 0xdb5, 0xca1, 0x28b, 0x410, 0x244, 0xf77, 0x62f, 0xe96, 0xb33, 0xf2b, 0x209, 0xf14,
-//         0x834, 0x65d, 0x0d2, 0xacd, 0x260, 0x2ed, 0xa69, 0xfcc, 0x900, 0xe6e, 0xa17, 0x080, 0x523,
-//         0x4c2, 0x5d1, 0xcee, 0x597, 0xa85, 0xb0d, 0x5d2, 0x7b4, 0x0dc, 0x6a9, 0x17c, 0x5e3, 0x15f,
-//         0xa09, 0xe57, 0xf07, 0x907, 0x930, 0x598, 0xdb0, 0x34d, 0x066, 0xa93, 0x8dc, 0x449, 0x505,
-//         0x1db, 0xeba, 0xe15, 0x65c, 0xbef, 0xd6e, 0x06e, 0x0ca, 0x934, 0xd87, 0x64a, 0x9d8, 0x8df,
-//         0x2d3, 0x3fe, 0x5a7, 0x609, 0x1c0, 0x611, 0x4bc, 0xe4c, 0x31c, 0x829, 0x84e, 0xada, 0xa51,
-//         0x316, 0x588, 0x7a2, 0xcff, 0xfb0, 0x43e, 0xd31, 0x817, 0xde6, 0x951, 0x9b5, 0x8c3, 0xa33,
-//         0xdc3, 0xa0e, 0x762, 0x3e4, 0x77e, 0x21a, 0x9c6, 0xe2e, 0xc91, 0xf06, 0x162, 0x53d, 0x94e,
-//         0x4df, 0x8d9, 0x7f0, 0xe5d, 0x6d8, 0xfb6, 0x918, 0x06b, 0xda6, 0x80b, 0x291, 0x4dc, 0x053,
-//         0x284, 0x2de, 0x8ad, 0x665, 0xa21, 0xb15, 0xdcf, 0x533, 0x39e, 0xcd7, 0x1ee, 0x80c, 0x0b2,
-//         0x313, 0xa3e, 0x819, 0x1d7, 0x52e, 0x3d0, 0xdc7, 0x041, 0xbc2, 0xe93, 0x368, 0xedd, 0x7d0,
-//         0x006, 0x637, 0x7cb, 0x693, 0xd4e, 0x9df, 0x74d, 0x565, 0xc66, 0x540, 0x60d, 0x07a, 0x7d4,
-//         0x0fd, 0x700, 0x532, 0x23a, 0x2c3, 0xf6a, 0x377, 0x7ff, 0x71e, 0x136, 0x571, 0x50c, 0x568,
-//         0xe05, 0x6ec, 0xd7c, 0xa56, 0xe01, 0xa6c, 0x715, 0x1ed, 0xb97, 0x76e, 0x255, 0xf26, 0x6e8,
-//         0xbbf, 0xb9d, 0xb93, 0x3a4, 0x59b, 0x9b2, 0x9ac, 0xb62, 0xfbd, 0xe36, 0xa0c, 0xe7f, 0x71b,
-//         0xe1c, 0xfda, 0xbce, 0xd7f, 0xe95, 0xeef, 0xa2d, 0x9f7, 0xaec, 0xd69, 0x418, 0x981, 0x8b9,
-//         0xee0, 0x087, 0x7c6, 0xac3, 0x2c5, 0x9e9, 0xf6d, 0x582, 0x3af, 0x115, 0x3bf, 0x32c, 0xdce,
-//         0x891, 0x56d, 0x973, 0xa95, 0x592, 0x61c, 0xfa0, 0xb73, 0x52a, 0xae3, 0x1a0, 0xf43, 0x4b7,
-//         0x332, 0x26b, 0xeb6, 0x397, 0xb96, 0xf24, 0x2cd, 0x16f, 0x49a, 0x81a, 0xf46, 0x409, 0xdac,
-//         0x3cc, 0xa5e, 0x696, 0x528, 0xcfa, 0xcc4, 0xda5, 0x938, 0x98f, 0xe45, 0x405, 0xa67, 0xe25,
-//         0xe64, 0x4e3, 0x12a, 0x361, 0x960, 0x78f, 0x474, 0x48a, 0x1bf, 0x9ce, 0xea2, 0x4da, 0x09e,
-//         0x34a, 0xff0, 0xb8a, 0xea7, 0x596, 0x84f, 0xe9e, 0x170, 0x7ee, 0x9ba, 0x7f8, 0x194, 0xe13,
-//         0x8cc, 0x81e, 0x4d7, 0x461, 0x4e9, 0x4a3, 0x905, 0xf0e, 0xc82, 0x868, 0xd30, 0x75b, 0x09b,
-//         0x7b9, 0x63e, 0x916, 0xc8f, 0x0a2, 0xf48, 0xee6, 0x6e5, 0x870, 0x610, 0x737, 0xd03, 0xacb,
-//         0x09a, 0x5a8, 0x952, 0x709, 0xc4d, 0xd9d, 0x1f2, 0xa96, 0x1e3, 0x944, 0x896, 0xf65, 0xb13,
-//         0xd53, 0xf81, 0x2c8, 0x723, 0xa8a, 0x190, 0x148, 0x2b2, 0x300, 0x85c, 0xa2a, 0x62a, 0xb57,
-//         0x2ee, 0xeec, 0x4e6, 0xd3c, 0x247, 0x77c, 0x7e9, 0x66c, 0x287, 0x38f, 0x3f1, 0xbe1, 0x1f0,
-//         0xb4b, 0xa35, 0x789, 0xd15, 0x4e0, 0xb11, 0x53f, 0xaa5, 0x7d8, 0xbb5, 0xc15, 0xd79, 0xa01,
-//         0x4aa, 0x1d5, 0xeae, 0xa32, 0x195, 0x430, 0xde2, 0x2a3, 0xd54, 0x64c, 0x4eb, 0xc86, 0x3ab,
-//         0xa0f, 0x5a0, 0xa7c, 0xb9c, 0x37d, 0x788, 0x328, 0x79d, 0xd6b, 0x03d, 0x496, 0xf01, 0x81b,
-//         0x73d, 0xae2, 0x09c, 0xa6b, 0xa6f, 0x8c0, 0x6c5, 0x722, 0x745, 0x7fe, 0xb54, 0x692, 0xd04,
-//         0xc2c, 0x327, 0xd71, 0xde0, 0xc02, 0xdc6, 0x3c6, 0x7e0, 0x3a8, 0xbac, 0x60b, 0x691, 0x7b2,
-//         0x29a, 0x7df, 0x576, 0x00f, 0x5d8, 0x390, 0x662, 0xca6, 0x0d0, 0x986, 0x30b, 0xfcb, 0x74b,
-//         0x46e, 0xfdb, 0xec6, 0x147, 0xba9, 0xa5b, 0x161, 0x079, 0x1de, 0xfdf, 0x30d, 0xdf2, 0xda3,
-//         0x7d5, 0xeaf, 0xb45, 0xee9, 0x9e0, 0x206, 0x8d5, 0x381, 0x899, 0x4ab, 0x530, 0x15a, 0x459,
-//         0x388, 0x959, 0x56e, 0xb8f, 0x677, 0x8d1, 0x3c0, 0x58c, 0xd81, 0x6dc, 0x10c, 0xe86, 0xec8,
-//         0x69e, 0xf5f, 0xfbf, 0xc0a, 0xcd1, 0x487, 0x8c5, 0x0f0, 0xdca, 0x9f5, 0x99a, 0x6ac, 0x7da,
-//         0xb65, 0xe67, 0xa62, 0xe5f, 0x7de, 0xc7c, 0xe52, 0x5bc, 0x7ea, 0xb9e, 0x2f8, 0xbff, 0x040,
-//         0x44f, 0xf4e, 0xdbf, 0xe63, 0x0fe, 0x2ec, 0x0a0, 0x985, 0x138, 0x06f, 0x772, 0xe5e, 0x102,
-//         0xd17, 0xc45, 0x67b, 0x675, 0xba2, 0xd82, 0x219, 0xbe2, 0x374, 0x5d6, 0x618, 0xe00, 0x929,
-//         0x479, 0x352, 0x46a, 0x6f2, 0x9ca, 0x769, 0x271, 0x7b8, 0xe26, 0xcdf, 0xe55, 0xefb, 0xd11,
-//         0x336, 0x7d9, 0xf13, 0x451, 0xc60, 0x1dd, 0xbd8, 0x886, 0x086, 0x7e3, 0xbe0, 0xd75, 0x7a0,
-//         0x91a, 0xa84, 0x391, 0xa42, 0xc76, 0xdf7, 0x552, 0x5e1, 0xead, 0x408, 0x4ff, 0x7bb, 0xc31,
-//         0x278, 0x95b, 0x419, 0x2f2, 0x15d, 0xa8d, 0x497, 0xd7d, 0x39f, 0x6a7, 0x803, 0x5c8, 0xfe0,
-//         0x527, 0x165, 0x23e, 0xb34, 0x9a9, 0xa37, 0x031, 0xf15, 0x7e7, 0xda8, 0x976, 0x118, 0xef0,
-//         0x561, 0x212, 0x10f, 0x8c1, 0xce8, 0xe1e, 0x8bc, 0x5b1, 0xcc5, 0xba3, 0x661, 0x242, 0x345,
-//         0x4c5, 0xcdd, 0x6b4, 0xc2e, 0xa34, 0x9e3, 0x227, 0xf66, 0x4d2, 0x866, 0x456, 0x060, 0x567,
-//         0xef1, 0xf64, 0xab1, 0x112, 0x919, 0xc34, 0x3c8, 0xb0f, 0xe5b, 0x02a, 0xd34, 0x3b9, 0x439,
-//         0x9bd, 0x059, 0x349, 0xfa2, 0x5b9, 0xf85, 0x208, 0x207, 0x61f, 0x6f9, 0x45f, 0x72a, 0xde3,
-//         0x688, 0x320, 0x656, 0x1b4, 0xec9, 0xa07, 0x881, 0x853, 0x277, 0x4fa, 0xfa4, 0x5c7, 0xb72,
-//         0x488, 0xd62, 0x0e3, 0x0c9, 0xc69, 0x1a9, 0xb6f, 0xd66, 0xdb8, 0xffd, 0x3d1, 0x728, 0x21b,
-//         0xf38, 0xd1e, 0x1a2, 0x66d, 0x88a, 0x6ad, 0x7b6, 0x18f, 0x9bf, 0x630, 0x980, 0x0fb, 0x87c,
-//         0xff9, 0x9bc, 0x589, 0x851, 0x169, 0x331, 0x80f, 0x5ae, 0x2b5, 0x7e4, 0xc38, 0x0ba, 0x27f,
-//         0x08c, 0xf92, 0x05b, 0x699, 0x233, 0xb3b, 0x1fe, 0x9e2, 0xf5e, 0xfa9, 0x928, 0x550, 0x369,
-//         0x779, 0xdde, 0x3eb, 0x9e5, 0x39c, 0x0e8, 0x475, 0xbf9, 0x12d, 0x56c, 0xd8a, 0xe69, 0xb08,
-//         0x337, 0xb1b, 0x742, 0x657, 0xa44, 0xbc7, 0x252, 0x9cb, 0x761, 0x6e6, 0xae0, 0x579, 0x03b,
-//         0x44c, 0xe6c, 0x875, 0x6d2, 0x9d3, 0x19e, 0x883, 0x804, 0x032, 0xc39, 0x522, 0xba1, 0x4f9,
-//         0xdd2, 0xeb8, 0xd93, 0x5e2, 0x2a4, 0x4b9, 0x8f9, 0x585, 0x050, 0x83b, 0x123, 0xda7, 0xd0e,
-//         0xc54, 0x2dc, 0x267, 0x6db, 0x5d0, 0xb83, 0x5b4, 0xf84, 0xc37, 0x21f, 0x680, 0x622, 0xf3a,
-//         0xac0, 0x26f, 0x513, 0x308, 0x283, 0x248, 0x83d, 0x18e, 0xbba, 0x4a0, 0x97e, 0xfd3, 0x203,
-//         0x5a1, 0x9f6, 0x240, 0xae1, 0x697, 0x87b, 0xade, 0x5a5, 0x12f, 0xebc, 0x7a9, 0x257, 0x469,
-//         0x02c, 0x28c, 0xaab, 0x79f, 0x304, 0xd9e, 0xe28, 0xcb8, 0x64f, 0x3e7, 0xef2, 0xacf, 0x8ab,
-//         0x3a1, 0x294, 0x45a, 0xe1b, 0x1ba, 0xcb6, 0x759, 0x7af, 0x6ce, 0xc17, 0x687, 0x503, 0xfd6,
-//         0x717, 0xac8, 0xbd9, 0x7fd, 0x26e, 0x570, 0x5db, 0xc11, 0x54b, 0x35c, 0x270, 0x19a, 0x920,
-//         0x77a, 0xda9, 0x322, 0x860, 0x007, 0x1f1, 0x9de, 0x171, 0xb46, 0xc1d, 0x996, 0xd27, 0xd0c,
-//         0x3d8, 0xe32, 0x8a3, 0x4be, 0x414, 0x91b, 0xb09, 0x539, 0xcac, 0x380, 0x935, 0xcaa, 0xf8c,
-//         0x61b, 0x92e, 0xdd0, 0x636, 0xb82, 0xb0c, 0x7d6, 0xc96, 0x1b9, 0x67d, 0x6d0, 0xcf7, 0xc6f,
-//         0x5e7, 0x786, 0x98b, 0xb88, 0xd40, 0x0c6, 0x94c, 0x2c6, 0xf72, 0x733, 0x821, 0x3e0, 0x72f,
-//         0x355, 0x335, 0x9da, 0x51f, 0xf11, 0xa25, 0xeb4, 0xd86, 0xd57, 0x6e0, 0xf4a, 0xb20, 0xca3,
-//         0xf70, 0x648, 0xcc1, 0xdbe, 0xc0c, 0xe6a, 0x358, 0x96e, 0x6e2, 0x83c, 0x03c, 0xab8, 0xec2,
-//         0xc4e, 0xd5a, 0xbde, 0x8d2, 0x01e, 0xa20, 0x7f1, 0xb5e, 0x005, 0x498, 0xa05, 0xf17, 0xd78,
-//         0x80e, 0x844, 0x2f7, 0x0a9, 0x978, 0x93e, 0x863, 0x6f7, 0x254, 0x4fe, 0x1c2, 0xcbb, 0x234,
-//         0x8fe, 0x36b, 0xf45, 0x557, 0x988, 0x189, 0xefc, 0x002, 0x2f6, 0xfd2, 0x36a, 0xa3c, 0xdb1,
-//         0xe56, 0xcb1, 0x314, 0x282, 0x366, 0x547, 0xf88, 0x602, 0x092, 0xff6, 0x2b0, 0xe9b, 0xfa7,
-//         0x9a2, 0xc8a, 0xaf8, 0xbad, 0xbd7, 0x8d8, 0x993, 0xb3f, 0xbbc, 0x849, 0xf10, 0xed3, 0xa2f,
-//         0xf4b, 0x591, 0x235, 0xb7c, 0xe61, 0x2a9, 0x2b7, 0xf39, 0x712, 0x0ce, 0xc1c, 0xb6b, 0x198,
-//         0x91d, 0x05c, 0xc01, 0x125, 0xc19, 0xb9b, 0xf37, 0xb36, 0x2ba, 0x0d3, 0xfc5, 0xa77, 0x2c2,
-//         0x843, 0xa10, 0x50e, 0x857, 0xc56, 0xce9, 0x6ff, 0xa36, 0xd24, 0xf09, 0x695, 0xa58, 0xa78,
-//         0xfed, 0x7a1, 0xfcd, 0x0c8, 0xae9, 0xb16, 0xce3, 0x4f5, 0xa7b, 0x074, 0xbd4, 0x013, 0x1af,
-//         0x142, 0x3d5, 0x864, 0x396, 0x92c, 0xc1f, 0xae4, 0x354, 0xb86, 0x619, 0x27b, 0x82b, 0xaf1,
-//         0xe62, 0x4fb, 0xb90, 0xe70, 0x8ed, 0xb67, 0xcf3, 0xd2c, 0x373, 0x70c, 0x92d, 0x649, 0xa61,
-//         0x472, 0x258, 0xc8b, 0xf58, 0x16c, 0x2d2, 0x376, 0x7c5, 0x8bb, 0x28a, 0x3e6, 0x50f, 0xf21,
-//         0x2d6, 0x48f, 0x1ca, 0xad3, 0x925, 0x9d1, 0xb3e, 0x5eb, 0x40f, 0x797, 0x4c6, 0xb2a, 0x9d9,
-//         0x6b3, 0xc28, 0xfd8, 0x57c, 0x107, 0x79c, 0xce4, 0xe91, 0xba7, 0x587, 0x643, 0xd16, 0x71f,
-//         0x575, 0x909, 0xee5, 0xc04, 0x7b0, 0x4ef, 0x983, 0xeb1, 0x792, 0xfb7, 0xf22, 0x94b, 0x12c,
-//         0x8c2, 0x100, 0xe48, 0xf97, 0x30c, 0x00c, 0x45d, 0x36e, 0xcba, 0x6d9, 0x7bf, 0x0b8, 0x08f,
-//         0x6c0, 0xe0e, 0x4f1, 0xc47, 0xaa4, 0x176, 0x558, 0x7c0, 0xe68, 0x0d1, 0x24c, 0x85a, 0x0b1,
-//         0x7dd, 0x360, 0xaad, 0xb0b, 0x6bf, 0x3d3, 0x9c2, 0x628, 0x548, 0xe43, 0xc58, 0x70a, 0xc2a,
-//         0x58d, 0x17e, 0xad1, 0x348, 0xe8c, 0x6aa, 0xaf5, 0x49e, 0x11f, 0x634, 0xe44, 0x990, 0x910,
-//         0xa88, 0xc6a, 0x3ef, 0x114, 0x2c7, 0xfd0, 0xb44, 0x44d, 0x201, 0xc8e, 0x288, 0xb5d, 0x2bd,
-//         0xd28, 0x50b, 0xbcc, 0x773, 0xc8d, 0x213, 0xa3b, 0x726, 0xa76, 0x286, 0x603, 0x2e4, 0x2aa,
-//         0xa66, 0xed9, 0x068, 0x22e, 0x089, 0xc46, 0xd97, 0xee4, 0x8a7, 0x6cf, 0xaf0, 0x808, 0xa86,
-//         0x7bd, 0xb61, 0x7fb, 0x214, 0x847, 0x908, 0xe42, 0xb64, 0x8db, 0x8b8, 0xa72, 0xbb3, 0x95c,
-//         0xfc2, 0x62c, 0x036, 0x9c7, 0xd83, 0x600, 0xec7, 0x586, 0xeee, 0x2f4, 0xac9, 0x968, 0x478,
-//         0x61a, 0x7be, 0x0a6, 0x9b0, 0x1a3, 0xfe5, 0x98c, 0x1e4, 0x31f, 0xf53, 0x8b4, 0x906, 0x893,
-//         0x1c1, 0xd42, 0xbaf, 0x714, 0xcc3, 0xd90, 0x812, 0x5c6, 0xd72, 0x38d, 0xdcb, 0xbf8, 0x47d,
-//         0x19b, 0x22b, 0x57d, 0x626, 0x924, 0x6cc, 0xd1c, 0xf12, 0x251, 0x946, 0x1a4, 0x750, 0xcd6,
-//         0x330, 0x3bc, 0xb43, 0xc6d, 0x20a, 0x038, 0xe40, 0xbea, 0x3c7, 0x65f, 0x52d, 0x2db, 0x6fe,
-//         0xea1, 0xf9c, 0xda4, 0xa9c, 0xf91, 0xe92, 0x081, 0x82e, 0x0c1, 0x4f7, 0xb91, 0x494, 0xbe7,
-//         0xd0f, 0xfe4, 0xd56, 0x077, 0xf98, 0x72c, 0x1bb, 0x0fa, 0xa9a, 0xa65, 0x458, 0xed1, 0x346,
-//         0x9db, 0x273, 0xdeb, 0x70e, 0xe31, 0xbb4, 0x9b6, 0x29f, 0x9cc, 0xb5f, 0x37f, 0xa90, 0x326,
-//         0xf68, 0x329, 0x2d8, 0x42c, 0x35e, 0xf63, 0x652, 0x97f, 0xe87, 0x9dd, 0x262, 0xcf2, 0xf7a,
-//         0x1aa, 0x14b, 0xb5a, 0x754, 0x91e, 0x191, 0x416, 0x5d4, 0xd05, 0x186, 0x69f, 0x463, 0x5f2,
-//         0x363, 0x6a8, 0x77f, 0x8e3, 0xa7f, 0xe3a, 0x155, 0x4bd, 0x5e5, 0x8ec, 0x044, 0xeab, 0x3f3,
-//         0xc2d, 0x63f, 0xf57, 0x31b, 0xb5c, 0x122, 0xf36, 0xf0c, 0xe83, 0x3d9, 0x1e7, 0x734, 0x2e2,
-//         0x382, 0x03a, 0xdf9, 0x93b, 0xded, 0x8fa, 0x943, 0xf2c, 0x59e, 0x89a, 0x065, 0x1cb, 0x86e,
-//         0x91c, 0xfe6, 0x8ea, 0xb0a, 0x33d, 0x2ca, 0x507, 0x16b, 0x7ef, 0xdfc, 0xdad, 0x740, 0xe9d,
-//         0x3fb, 0xd77, 0xb68, 0xa91, 0x8e8, 0x3ce, 0x58e, 0xd0d, 0x76d, 0x280, 0x632, 0x420, 0xc61,
-//         0xe4d, 0x7cf, 0x9c1, 0x6ef, 0xb99, 0x757, 0x4a6, 0x926, 0x302, 0x7ac, 0xb98, 0x110, 0x93c,
-//         0x56f, 0xf9e, 0x3e1, 0xfe9, 0x979, 0x7d2, 0xcad, 0xb37, 0x07b, 0xbb8, 0xc21, 0x01d, 0x56b,
-//         0xeb7, 0x027, 0x3c4, 0x81d, 0x1c6, 0x41b, 0xd65, 0x275, 0xd13, 0xd94, 0xcae, 0x476, 0xccf,
-//         0x3de, 0x577, 0x88b, 0xa46, 0xc57, 0xb94, 0xa1a, 0x8e5, 0x59f, 0x7a7, 0x106, 0xa5c, 0x129,
-//         0x164, 0x187, 0x32b, 0xdd1, 0x32f, 0x9d4, 0xcbc, 0xc5a, 0x512, 0xfb9, 0xbcf, 0xe59, 0x3e8,
-//         0x264, 0xaa8, 0xbfe, 0x752, 0x799, 0x5d5, 0x0b6, 0xb19, 0x580, 0xea8, 0x852, 0x29c, 0xfb4,
-//         0x15c, 0xea0, 0x8f3, 0xd9f, 0x5c0, 0x672, 0x40b, 0x974, 0xdb7, 0x6fb, 0x241, 0xca5, 0x31d,
-//         0x775, 0x104, 0x624, 0x305, 0x9a8, 0x5cb, 0xf1a, 0x7cc, 0x17b, 0xeb9, 0xe5c, 0x9c4, 0x38c,
-//         0x524, 0xf1f, 0x093, 0x823, 0x179, 0xc93, 0xdb6, 0x078, 0xe9c, 0xc36, 0x8ac, 0x620, 0x29e,
-//         0xfe3, 0x12b, 0x544, 0xf3d, 0x2e7, 0x6e1, 0x52b, 0xb87, 0x301, 0x4e2, 0x268, 0x99c, 0x0af,
-//         0x279, 0x995, 0x131, 0x65a, 0xd84, 0x070, 0xebf, 0xdc9, 0x1cf, 0xb05, 0xa02, 0x357, 0x4ec,
-//         0x404, 0x811, 0x814, 0xc68, 0x237, 0x91f, 0x154, 0xb3c, 0xed0, 0x4ae, 0xf82, 0x7f2, 0x9ae,
-//         0x11c, 0xb03, 0xa73, 0xad9, 0x629, 0xd18, 0x259, 0x184, 0x0ae, 0x554, 0xd14, 0x02f, 0xd06,
-//         0x6a4, 0xbe8, 0x455, 0xdec, 0xc9a, 0x57e, 0x721, 0x774, 0xb79, 0x543, 0xe06, 0x133, 0x94d,
-//         0xc48, 0x4de, 0x226, 0xf93, 0xbdd, 0xc7f, 0x74e, 0xc83, 0x097, 0x232, 0x51b, 0x97a, 0xc35,
-//         0xc80, 0x39a, 0x932, 0x2fb, 0xedc, 0x2e8, 0x3e3, 0x067, 0x7f3, 0x5c2, 0x79e, 0x28f, 0x117,
-//         0xc4f, 0x5e0, 0x043, 0x760, 0x20d, 0x6f8, 0x51d, 0x9d7, 0xbc1, 0x76f, 0xd8e, 0xb95, 0x615,
-//         0x529, 0x698, 0xa1d, 0xcc2, 0x578, 0x7c8, 0xab2, 0xa45, 0x59a, 0x6af, 0x0f6, 0x917, 0x33c,
-//         0x18b, 0x5fa, 0xad7, 0x0c3, 0x3c9, 0x0e2, 0x3ad, 0x6e3, 0xac1, 0x265, 0xae5, 0x8a4, 0x573,
-//         0xbfb, 0x8ce, 0x551, 0xb2f, 0x65b, 0x8af, 0xd02, 0x069, 0xa80, 0x04d, 0xfeb, 0x2b8, 0x448,
-//         0xef6, 0x9b8, 0x57a, 0xfa6, 0x1ab, 0xa98, 0x8d3, 0x8e4, 0x11b, 0xd8c, 0x3d4, 0x4a2, 0x1dc,
-//         0x13e, 0xe49, 0x521, 0x659, 0x13d, 0x82f, 0x2b9, 0x29d, 0x4a7, 0x2cc, 0x755, 0xf1d, 0x236,
-//         0x617, 0xdc8, 0xa30, 0x132, 0x914, 0x3a6, 0x3b8, 0x3a9, 0xd48, 0xa59, 0xf59, 0x05d, 0x6b8,
-//         0x5ff, 0x001, 0x438, 0x6e9, 0x668, 0x542, 0x31a, 0x4ad, 0xaaa, 0x967, 0x141, 0xfc3, 0xbbe,
-//         0x47b, 0xf9b, 0xb41, 0xbd2, 0xfc6, 0xf47, 0x38a, 0xe33, 0x1d6, 0x4ee, 0x7c9, 0x7a6, 0xd01,
-//         0x09f, 0x89c, 0x1d1, 0x18c, 0xc4a, 0xdf5, 0xd38, 0xd33, 0x04e, 0x14f, 0xc7b, 0x7f9, 0x48d,
-//         0x590, 0x991, 0x673, 0xdcc, 0xa27, 0x82c, 0xa7e, 0xb06, 0xfd4, 0xb29, 0x816, 0x10a, 0x2cb,
-//         0xe78, 0x166, 0x06a, 0xb28, 0x076, 0x4c8, 0xf52, 0xf62, 0xfbb, 0x647, 0x8eb, 0xb38, 0xa7a,
-//         0xbec, 0x511, 0x7d7, 0x239, 0xbb6, 0xd1b, 0x55a, 0xe3f, 0x1e8, 0x6d1, 0x1a6, 0xbb0, 0xef3,
-//         0x5f0, 0x807, 0xe50, 0x19f, 0x0da, 0xb25, 0xad8, 0x20e, 0x683, 0xe20, 0x9d6, 0x63b, 0x0ab,
-//         0x963, 0x5b8, 0xdef, 0x177, 0x442, 0xb01, 0x1f7, 0xb49, 0x2d0, 0xc5f, 0x180, 0x18d, 0x6e7,
-//         0xd85, 0x73e, 0xe27, 0x869, 0x9dc, 0xdea, 0xfe1, 0x1ff, 0x367, 0xcbd, 0xfcf, 0xebb, 0x5f8,
-//         0xe4b, 0x63d, 0xd37, 0x9b1, 0xa74, 0x5bb, 0x887, 0x8da, 0xe0d, 0x4f6, 0x437, 0xec0, 0x6da,
-//         0xca9, 0x553, 0x261, 0x0f8, 0x876, 0xdf6, 0x517, 0xcd2, 0xe80, 0xe88, 0xed2, 0x146, 0x317,
-//         0x508, 0xfc1, 0x127, 0xdf0, 0xe4a, 0x477, 0x519, 0x4f8, 0x748, 0x5ab, 0x4b8, 0x75f, 0x606,
-//         0xeb3, 0xabb, 0xab5, 0x9e4, 0xecc, 0x753, 0x43d, 0x526, 0x5fd, 0xb17, 0xe77, 0xbe9, 0x2b6,
-//         0xf74, 0x8d6, 0x842, 0xf3f, 0x030, 0x24b, 0xf32, 0x704, 0x640, 0x904, 0x4f0, 0xb52, 0x927,
-//         0x75d, 0xde8, 0xd4d, 0xa1f, 0x490, 0x69b, 0xd1a, 0x1b3, 0x096, 0x400, 0x64b, 0x93f, 0x5e6,
-//         0xf44, 0xd6f, 0xddf, 0x465, 0x07f, 0x073, 0x221, 0x3fc, 0x9f0, 0x85d, 0xa2c, 0xc06, 0x460,
-//         0xec5, 0x913, 0xd5b, 0x9fe, 0x885, 0xdfb, 0x9f2, 0x062, 0x945, 0x3ec, 0xa75, 0x83e, 0xf7c,
-//         0x0cc, 0xd3d, 0x175, 0x26d, 0x36f, 0x3d2, 0x4a9, 0xba4, 0xe2d, 0x1ef, 0xd5c, 0x197, 0xc79,
-//         0x826, 0x94a, 0x8e7, 0x95a, 0x5ea, 0x1e0, 0xe7d, 0x806, 0x5cf, 0xd5f, 0x1cc, 0x964, 0xb7e,
-//         0xcd9, 0x1ad, 0xf76, 0x7ae, 0xdbd, 0x22a, 0x828, 0x892, 0x739, 0xf40, 0x77d, 0x595, 0x833,
-//         0x45e, 0x042, 0x39d, 0x7e8, 0xd10, 0x9af, 0xdd3, 0x7ba, 0xdc4, 0x1a7, 0x650, 0x6a3, 0x7f4,
-//         0x150, 0x2a7, 0x52f, 0xced, 0x8aa, 0x319, 0xaed, 0x039, 0x638, 0x3c5, 0xa9d, 0x0e0, 0xf5c,
-//         0xd51, 0x8e9, 0x344, 0xcb3, 0x3a3, 0x6b0, 0x878, 0x229, 0x21c, 0x8cf, 0x061, 0xe99, 0xb85,
-//         0xb7d, 0xa4a, 0x297, 0x54f, 0x6df, 0x8e1, 0x46b, 0xa18, 0x763, 0x791, 0x4bf, 0x204, 0xbbb,
-//         0x4d0, 0xf1c, 0x35b, 0xd5e, 0x7ec, 0x183, 0xbc6, 0x211, 0x79b, 0xaa9, 0x594, 0x78b, 0xba8,
-//         0x5b5, 0x015, 0x6be, 0x307, 0xca8, 0x4fd, 0x464, 0x86c, 0x429, 0xd73, 0xf33, 0xed8, 0x3b2,
-//         0xfd9, 0xcdc, 0x8a9, 0x111, 0x32a, 0x292, 0x491, 0x4b1, 0x4e1, 0xe71, 0x2a1, 0xe2c, 0x898,
-//         0x5c4, 0xb5b, 0x4b4, 0x2af, 0x072, 0x9fc, 0xb12, 0xbaa, 0xfc7, 0x5d3, 0x805, 0x003, 0x08b,
-//         0x0c2, 0xc63, 0x116, 0x021, 0x2f5, 0x3b4, 0x3ea, 0x2fe, 0xa8e, 0x22c, 0xc78, 0x364, 0x238,
-//         0x0c7, 0x6a2, 0x950, 0xe8b, 0xaae, 0xb21, 0x73a, 0xe3b, 0x0ea, 0x47f, 0xb4c, 0x560, 0x2ab,
-//         0x5b7, 0xe47, 0x8f7, 0xcbe, 0x6ee, 0x5e4, 0xc5c, 0x608, 0xc50, 0xb14, 0xdcd, 0x3ca, 0x8cd,
-//         0x462, 0x555, 0x1ec, 0x054, 0xd99, 0x091, 0xf50, 0x9f3, 0x59c, 0xafd, 0x441, 0xc3d, 0xfee,
-//         0xaf3, 0x4c4, 0x5ca, 0x149, 0xbc8, 0xd1d, 0xe2f, 0x4cd, 0x66e, 0xb4e, 0xad4, 0x99d, 0xd20,
-//         0xd92, 0xed6, 0xd98, 0xeed, 0x97c, 0xe2a, 0x0db, 0xc73, 0xd7e, 0x15b, 0xf99, 0x5fc, 0x387,
-//         0xa64, 0x0a7, 0xfc8, 0x2bb, 0xb1d, 0xe3c, 0xb8c, 0xc74, 0x480, 0xc77, 0xa9f, 0xb89, 0x454,
-//         0xcb0, 0x0ff, 0x6d4, 0xf0a, 0x7b5, 0x1f5, 0x263, 0xad5, 0x1b5, 0x371, 0xe09, 0x645, 0x8b6,
-//         0x535, 0x63c, 0xccc, 0x5af, 0x8c7, 0xb35, 0x1ac, 0x865, 0xcde, 0x48b, 0x375, 0x4dd, 0xb31,
-//         0x109, 0x800, 0xbae, 0x1b1, 0x351, 0x289, 0xf75, 0xb2b, 0x43a, 0x778, 0xe8d, 0xdb2, 0xa81,
-//         0xfce, 0xa53, 0xac7, 0x562, 0x6eb, 0x468, 0xc0d, 0x04a, 0xde5, 0xa97, 0x415, 0x0aa, 0x70b,
-//         0xbf3, 0x9aa, 0x1f6, 0x423, 0x70d, 0xe0c, 0x82a, 0x0c0, 0x679, 0x1b7, 0xa4f, 0xc32, 0xac2,
-//         0xfec, 0xab4, 0x13a, 0x70f, 0x25a, 0x04b, 0xaba, 0x484, 0x309, 0x4c3, 0x957, 0x35d, 0x3bb,
-//         0x30e, 0xb1c, 0x839, 0xae8, 0x9ea, 0x27d, 0x5df, 0xdd8, 0xf20, 0x402, 0x7cd, 0x684, 0xb47,
-//         0x685, 0xa7d, 0xed5, 0x8f8, 0xe7c, 0x10e, 0x525, 0xcaf, 0x7f5, 0xcb5, 0xb80, 0x00d, 0xb6a,
-//         0x4b2, 0xe2b, 0xd2d, 0xb18, 0x735, 0x84d, 0x6ea, 0xafb, 0x3cb, 0x6d6, 0xb4d, 0x784, 0xfe7,
-//         0xb7b, 0xc5b, 0xbd3, 0x5c1, 0xb00, 0x417, 0x8f4, 0x6bb, 0x26c, 0xcab, 0xcea, 0xe46, 0x342,
-//         0xf8e, 0x051, 0xf8f, 0x8fc, 0x167, 0x71a, 0x436, 0xa71, 0xbd1, 0x3dd, 0xda0, 0xc3c, 0x243,
-//         0x25c, 0xa12, 0xadb, 0xd00, 0xcc6, 0x0bc, 0x3dc, 0x6b9, 0xc71, 0x4c9, 0x915, 0x6fc, 0x701,
-//         0xb30, 0xd89, 0xf3b, 0x61d, 0x953, 0xb42, 0x047, 0xff3, 0x55d, 0x1c7, 0xf29, 0xc16, 0x413,
-//         0xaee, 0xd64, 0xd29, 0x9a6, 0xc9c, 0x245, 0x613, 0x42b, 0xcca, 0x159, 0xae6, 0xa6d, 0x0f9,
-//         0x253, 0x7dc, 0xef5, 0xa8b, 0xd68, 0x939, 0x62e, 0xec4, 0x749, 0x994, 0x5fb, 0xafa, 0xd36,
-//         0xbda, 0x8ee, 0xcb7, 0x3ba, 0x58b, 0xce0, 0x777, 0x0b9, 0xef4, 0xdb9, 0x87f, 0xbee, 0x6a6,
-//         0xc6b, 0x7bc, 0x8f1, 0x2bc, 0x574, 0x936, 0xc12, 0xb26, 0xa60, 0x0e9, 0x440, 0xb1e, 0xbc3,
-//         0x466, 0xb76, 0x94f, 0x4cc, 0xfbe, 0xfb3, 0x386, 0x323, 0x7e6, 0xe4f, 0x710, 0x0de, 0x34e,
-//         0x6d3, 0xc2f, 0x1ea, 0x8f6, 0x4e7, 0xd59, 0x3a5, 0x621, 0x06d, 0x711, 0xee1, 0xf6c, 0x3fd,
-//         0x948, 0xf7d, 0xe18, 0x222, 0x394, 0x25d, 0x4f2, 0x37e, 0x9c0, 0xb7a, 0xcb4, 0xf35, 0x1c4,
-//         0xabd, 0x470, 0x34f, 0xe35, 0x094, 0xdfd, 0x956, 0xb77, 0x25f, 0x84a, 0x71d, 0x68f, 0xee3,
-//         0x2dd, 0x049, 0x877, 0xfae, 0xd2a, 0x00e, 0xe41, 0xc5d, 0x011, 0xe5a, 0x88c, 0xa63, 0xc44,
-//         0xf8d, 0x0bf, 0xe84, 0x912, 0x8a6, 0x163, 0xaa1, 0x25b, 0x8a8, 0x689, 0x1df, 0x37c, 0x888,
-//         0x321, 0x783, 0x84b, 0x4cb, 0xdab, 0xe04, 0xd21, 0xd2b, 0x7a5, 0x736, 0xf05, 0xb4a, 0x0b4,
-//         0xb0e, 0xbab, 0xbf7, 0x000, 0x2b3, 0x49f, 0x7ed, 0xdaf, 0x73c, 0xf41, 0x9ff, 0x21d, 0x54d,
-//         0x9ec, 0xacc, 0x74c, 0x2c1, 0x87e, 0x1a1, 0xe74, 0xddb, 0x365, 0x6f5, 0xb51, 0xe38, 0x970,
-//         0xaf7, 0x1e2, 0xbdc, 0x731, 0xc89, 0xd25, 0x809, 0xbd0, 0x676, 0x87a, 0x24d, 0xbb2, 0xdd5,
-//         0x962, 0xf8a, 0x671, 0x75c, 0x623, 0x83f, 0xd55, 0x68b, 0x765, 0x3b0, 0x850, 0xf1b, 0xe97,
-//         0x3fa, 0xddc, 0xc23, 0xa52, 0xe19, 0x845, 0x1fa, 0x90f, 0x32d, 0xe34, 0xf80, 0xa6e, 0x44e,
-//         0xd32, 0xe6d, 0x199, 0xeda, 0x88e, 0x8e2, 0x99b, 0xe9f, 0xca7, 0x49c, 0xbc4, 0xe1a, 0x303,
-//         0x13c, 0x9f1, 0x818, 0x78e, 0xbdb, 0xcb9, 0x764, 0x01b, 0x27e, 0x082, 0x40c, 0x5c3, 0x1b8,
-//         0xe98, 0x4e4, 0x401, 0x269, 0x485, 0x4d8, 0x249, 0xea9, 0x7e2, 0xe75, 0x825, 0x052, 0x026,
-//         0x422, 0x837, 0x5e8, 0x395, 0xcc8, 0x10d, 0xbd6, 0x631, 0xa19, 0x7fa, 0x859, 0x706, 0x3b1,
-//         0xf56, 0x882, 0xa87, 0xc0f, 0xf34, 0x389, 0xa4b, 0x135, 0x5da, 0x3b7, 0x674, 0x5cc, 0xe79,
-//         0x412, 0x17a, 0xad6, 0xa43, 0x67c, 0xa47, 0x2df, 0x76c, 0xaff, 0x196, 0x2fd, 0xc41, 0x830,
-//         0x14d, 0x5ef, 0x820, 0x7d1, 0x230, 0x04f, 0x937, 0x6f0, 0x228, 0xa89, 0x59d, 0x96a, 0x566,
-//         0x50a, 0xbbd, 0x3f8, 0x58f, 0x785, 0x4f4, 0x69c, 0x781, 0x98a, 0xc92, 0x1a5, 0xaf2, 0xd7a,
-//         0x813, 0x5fe, 0x0b7, 0xbed, 0xc40, 0xfbc, 0x8b1, 0xfac, 0x5bf, 0x572, 0x966, 0x1fc, 0xece,
-//         0xc03, 0x9a1, 0xa79, 0x89b, 0x0c4, 0x36d, 0x17d, 0x989, 0xd4f, 0xecb, 0x11d, 0xbeb, 0x285,
-//         0x434, 0x03e, 0x894, 0xdd4, 0x0cb, 0x411, 0xd96, 0xd80, 0x51a, 0xbe4, 0xde1, 0x556, 0x84c,
-//         0x612, 0x815, 0x019, 0xc49, 0xc59, 0x538, 0x6c2, 0x378, 0xc8c, 0x54c, 0x113, 0x88d, 0x1f9,
-//         0xf3c, 0x678, 0xa92, 0xb6c, 0xf5d, 0xc95, 0x41d, 0x01c, 0x794, 0xab7, 0xfd1, 0x954, 0x009,
-//         0xd46, 0xd9c, 0x85f, 0x432, 0x8ca, 0x008, 0xf5b, 0x41f, 0xff5, 0x03f, 0x6a5, 0x8a5, 0x3c3,
-//         0x95e, 0xff1, 0x6a1, 0x099, 0x2c9, 0xa06, 0x8a0, 0xdc1, 0xc20, 0x8d0, 0x295, 0x7a3, 0xcbf,
-//         0xc4c, 0x5ec, 0x3e2, 0xe1f, 0xa0b, 0x95f, 0xcf5, 0x2a2, 0x224, 0xfdd, 0xb2c, 0x801, 0xad2,
-//         0x9f4, 0x47c, 0x058, 0x744, 0xcd0, 0xa4e, 0x4b3, 0x2d4, 0x62d, 0x664, 0xd0b, 0x473, 0x17f,
-//         0x841, 0x225, 0x5b3, 0xf55, 0x8b7, 0xfc4, 0xb69, 0xdbb, 0xe89, 0x3ed, 0xbf2, 0x41c, 0xe8f,
-//         0x5ac, 0x7ab, 0x025, 0xd5d, 0xd26, 0xfa3, 0x492, 0xa50, 0x81f, 0x998, 0x45c, 0xa38, 0x719,
-//         0x846, 0x399, 0x1b2, 0x3f5, 0xe6b, 0x78c, 0x51c, 0x6f1, 0xe7a, 0xc75, 0xc52, 0x017, 0xe39,
-//         0x8cb, 0xd2f, 0xf2f, 0x604, 0xce7, 0x098, 0x298, 0x639, 0xea4, 0x8f0, 0xe6f, 0x5f6, 0x8ae,
-//         0x8e6, 0xe3e, 0x0ee, 0xa8f, 0xcf9, 0xcd4, 0xb50, 0x1e9, 0xadf, 0x651, 0xf03, 0x4d4, 0x9fd,
-//         0x1c8, 0x9a3, 0xb55, 0xaa6, 0x471, 0xec1, 0x6d7, 0x3db, 0xcfe, 0x93d, 0xc5e, 0x4c7, 0x231,
-//         0x4ba, 0xb4f, 0x903, 0xb53, 0xf54, 0x3e5, 0xccd, 0x85e, 0x4f3, 0xbc0, 0xc99, 0xc22, 0x614,
-//         0x931, 0x5be, 0xab9, 0x9c3, 0x088, 0x453, 0xb48, 0x97b, 0x6cd, 0x033, 0xb3d, 0x6ca, 0xeca,
-//         0xd3a, 0x427, 0x095, 0xc7e, 0x581, 0x694, 0x6b5, 0xc64, 0x47a, 0x68c, 0x771, 0x824, 0x037,
-//         0xfdc, 0x601, 0xbfd, 0xa68, 0xe54, 0xcc9, 0xca2, 0x393, 0xa6a, 0x384, 0x997, 0x8c9, 0x35f,
-//         0x4b0, 0x4e8, 0x014, 0xaf6, 0xf08, 0x024, 0xaa2, 0xa13, 0x9fb, 0x767, 0x8c8, 0xc55, 0xca0,
-//         0xcc0, 0x2e1, 0x747, 0xb71, 0xb75, 0xf9d, 0x08e, 0xa2e, 0x62b, 0xd67, 0x403, 0x827, 0x333,
-//         0xcfb, 0xd44, 0x96f, 0xdda, 0x41e, 0x139, 0x02e, 0xef8, 0xa54, 0xabc, 0x1fd, 0x766, 0x7a8,
-//         0x42d, 0xef7, 0x341, 0x999, 0x616, 0x433, 0x5f5, 0x37a, 0xe0f, 0x8c4, 0xef9, 0xe37, 0x223,
-//         0xd43, 0x012, 0xe7e, 0x445, 0xb39, 0xa1e, 0x4fc, 0x707, 0xff2, 0x120, 0x2e9, 0xc70, 0x89d,
-//         0x372, 0xa3d, 0xf73, 0x2ce, 0x49d, 0x1c3, 0xc26, 0x029, 0x74a, 0x3ac, 0xe08, 0xe51, 0x5f4,
-//         0x705, 0xea6, 0x514, 0x515, 0x1ce, 0xc98, 0x3d6, 0xe12, 0xe23, 0xf30, 0xd49, 0xf2e, 0x370,
-//         0xffa, 0x0bb, 0xbf5, 0xfe8, 0x2ae, 0x3cf, 0xffe, 0x52c, 0x549, 0x153, 0xf02, 0x1be, 0x200,
-//         0xe03, 0x5de, 0x5b2, 0x447, 0xfab, 0x486, 0x6c3, 0xfc9, 0x992, 0x350, 0x9b4, 0x858, 0xbfc,
-//         0xd4a, 0x2d1, 0xf8b, 0xf9a, 0xda2, 0x5dd, 0x035, 0xf90, 0x05a, 0x444, 0xdf8, 0xf27, 0x07c,
-//         0x9c5, 0x49b, 0xb2e, 0x6fa, 0xbe6, 0x5c9, 0xc07, 0x7f7, 0x353, 0x57b, 0x6a0, 0x4c1, 0x80a,
-//         0x724, 0xcf8, 0x28d, 0x55b, 0x44b, 0x97d, 0x424, 0xc85, 0xa8c, 0x47e, 0xd60, 0x5a4, 0x3ee,
-//         0xff7, 0xa29, 0x593, 0xdae, 0x67f, 0x43c, 0x130, 0x500, 0x35a, 0x9e6, 0x7ad, 0xf9f, 0x9c8,
-//         0xf83, 0xee2, 0xace, 0xab0, 0xd3f, 0x873, 0xd70, 0x5a2, 0x66a, 0x144, 0x12e, 0x922, 0xd3b,
-//         0xe22, 0x398, 0x39b, 0xce5, 0xdd6, 0xaa3, 0xa1b, 0x018, 0x2a5, 0x71c, 0x2c0, 0xd1f, 0x168,
-//         0x24f, 0xc10, 0x23b, 0xdb3, 0xbd5, 0x5ba, 0x3b3, 0x9ee, 0xb7f, 0xc33, 0x641, 0x452, 0x324,
-//         0x7c4, 0xc81, 0xdfa, 0x1da, 0xfad, 0x518, 0x6bc, 0x428, 0x911, 0xdb4, 0x642, 0x655, 0xfaa,
-//         0x9eb, 0x6c1, 0xe24, 0x98e, 0x07d, 0x0dd, 0x8ba, 0x4ed, 0x33b, 0x92f, 0xa55, 0x8c6, 0xf60,
-//         0xf2a, 0xd0a, 0x392, 0x143, 0x9fa, 0x1a8, 0xcef, 0x172, 0x644, 0x1fb, 0x743, 0xc67, 0xd91,
-//         0x832, 0xc72, 0x495, 0x569, 0x2e3, 0xf04, 0xbdf, 0x0f2, 0xb9f, 0x1cd, 0x95d, 0x312, 0xf89,
-//         0xb1f,
-//     ];
-
-//     const EXPECTED: [u8] = [
-//         0x8a, 0x63, 0x79, 0x92, 0x0b, 0xec, 0x9b, 0x05, 0xd5, 0x25, 0x19, 0x60, 0x11, 0x6f, 0x9c,
-//         0xa1, 0x2a, 0x4f, 0x52, 0x89, 0x35, 0xb3, 0x35, 0x7a, 0x1f, 0x9a, 0x46, 0xbb, 0x66, 0x66,
-//         0xe4, 0xd9, 0xd0, 0xad, 0x7b, 0xa2, 0xef, 0x13, 0x4e, 0xf9, 0xb9, 0x5f, 0x0b, 0x28, 0xf2,
-//         0xd6, 0x27, 0x76, 0x58, 0xac, 0x9a, 0x1e, 0x3c, 0x9c, 0x1f, 0xdc, 0x9a, 0xb0, 0xaf, 0x44,
-//         0x3c, 0x73, 0xa5, 0xc8, 0x7b, 0xf3, 0xb5, 0x20, 0xa3, 0x1a, 0xf6, 0x5c, 0xfd, 0x0c, 0x7d,
-//         0xcf, 0x98, 0x3c, 0xdd, 0xe4, 0x5b, 0x63, 0x64, 0x09, 0xc9, 0xe5, 0x6e, 0xa7, 0xd2, 0xab,
-//         0x0f, 0xd2, 0xe3, 0x56, 0xe1, 0xdb, 0x2f, 0x80, 0x79, 0x99, 0x8b, 0x3f, 0x57, 0x32, 0x93,
-//         0x8c, 0x93, 0x7f, 0x3f, 0x54, 0xef, 0xd9, 0x61, 0x7b, 0x72, 0x12, 0x3f, 0x88, 0x49, 0x2a,
-//         0x93, 0xe8, 0x41, 0x32, 0xf6, 0x71, 0x64, 0x7b, 0x89, 0xae, 0xc1, 0x82, 0xf9, 0x54, 0x75,
-//         0x3b, 0x0f, 0x1e, 0x0e, 0x21, 0x73, 0xbb, 0x3a, 0x9e, 0xdc, 0xc5, 0xea, 0xaf, 0x72, 0x3a,
-//         0x53, 0x86, 0x21, 0x52, 0x10, 0xe7, 0xa0, 0xb4, 0x08, 0xd0, 0xeb, 0x4a, 0x4f, 0x38, 0x35,
-//         0xb9, 0x6d, 0x5e, 0x70, 0x32, 0x4f, 0x53, 0x7e, 0x86, 0xda, 0x91, 0xd4, 0x81, 0x76, 0x57,
-//         0xa3, 0x77, 0x8e, 0xb8, 0x34, 0x27, 0x6d, 0x04, 0x3b, 0x61, 0x79, 0x80, 0xbd, 0xbd, 0xfa,
-//         0x68, 0x5a, 0xcf, 0x05, 0x4c, 0x1e, 0xda, 0x75, 0xa7, 0xa7, 0xf9, 0x10, 0x1d, 0x76, 0xfa,
-//         0x0d, 0x68, 0xc1, 0x66, 0x30, 0x2c, 0x43, 0xb5, 0x73, 0xb8, 0xda, 0xd2, 0xae, 0x42, 0x55,
-//         0x2f, 0xf7, 0x14, 0x3c, 0x69, 0xb7, 0x70, 0x8b, 0xc6, 0x9d, 0xa6, 0x07, 0xf7, 0x08, 0xfd,
-//         0x55, 0xd7, 0xd6, 0x26, 0xd4, 0x45, 0x7f, 0x7b, 0x8c, 0x48, 0xe2, 0xde, 0x19, 0x7e, 0xc2,
-//         0x28, 0xd4, 0xc1, 0xaa, 0xde, 0x14, 0x3c, 0x9b, 0x79, 0xb3, 0xad, 0x89, 0x64, 0xe4, 0x41,
-//         0x17, 0x74, 0xea, 0x44, 0x4b, 0x47, 0x36, 0xbe, 0x63, 0x09, 0x80, 0xec, 0xe3, 0x14, 0x2c,
-//         0x74, 0x40, 0x29, 0x9a, 0xd2, 0x62, 0xa8, 0xa7, 0xed, 0x0c, 0x19, 0xbe, 0x11, 0x45, 0x7c,
-//         0x11, 0x98, 0x4c, 0xfd, 0x92, 0xbb, 0x82, 0xe3, 0x9d, 0x27, 0x0e, 0x20, 0x07, 0x7f, 0xeb,
-//         0x32, 0xb9, 0xaf, 0xd7, 0x07, 0x3e, 0x3a, 0x99, 0x05, 0x74, 0x38, 0x10, 0xbb, 0x25, 0xa8,
-//         0xd9, 0xb7, 0x29, 0xa9, 0xa8, 0xac, 0x7d, 0xd2, 0x92, 0x07, 0xcd, 0x6b, 0x71, 0xe1, 0xbb,
-//         0x21, 0x08, 0x5a, 0x3a, 0x89, 0xd9, 0xbf, 0xcf, 0x0b, 0x34, 0x73, 0xdf, 0x3c, 0xec, 0x99,
-//         0x66, 0xc2, 0xea, 0x9d, 0x16, 0xeb, 0xae, 0xa9, 0x1e, 0xf5, 0xf4, 0x84, 0xb3, 0x6c, 0xc8,
-//         0xdd, 0x00, 0x28, 0xd5, 0xb6, 0x93, 0x27, 0xc2, 0xff, 0x38, 0x47, 0x58, 0xf9, 0x66, 0x7c,
-//         0x64, 0x11, 0x24, 0xcc, 0x14, 0x0a, 0x49, 0xe0, 0x29, 0xfa, 0x71, 0x4e, 0xdc, 0xcc, 0x0d,
-//         0xf5, 0x40, 0x24, 0xbd, 0xea, 0x02, 0x27, 0x0a, 0x05, 0xea, 0x4b, 0x4e, 0xd0, 0x62, 0x49,
-//         0x86, 0x9c, 0x87, 0xa1, 0x18, 0x35, 0xca, 0x78, 0xe4, 0x09, 0x6d, 0x58, 0x2d, 0x1d, 0x5c,
-//         0x81, 0xe6, 0xe4, 0xe0, 0x2f, 0x78, 0x67, 0x4b, 0x8c, 0x7f, 0x85, 0x7e, 0xba, 0x26, 0xc0,
-//         0xcf, 0x60, 0x63, 0x33, 0xaa, 0x4c, 0xf3, 0xf6, 0xeb, 0x14, 0x0d, 0xd5, 0x71, 0xed, 0x2b,
-//         0x60, 0x8b, 0x8a, 0xc7, 0x6f, 0xa2, 0x11, 0x78, 0xde, 0x16, 0x19, 0x13, 0xb6, 0xde, 0x4c,
-//         0xeb, 0xcd, 0xdb, 0xc1, 0x56, 0x44, 0x37, 0x22, 0x1c, 0x63, 0xa9, 0xe8, 0xba, 0xd6, 0x78,
-//         0xe7, 0x00, 0xad, 0x61, 0xa9, 0xb5, 0x67, 0x86, 0x73, 0x10, 0x57, 0x15, 0xdf, 0x2b, 0xeb,
-//         0x5d, 0x60, 0xd0, 0x5c, 0x59, 0xcc, 0xe4, 0x44, 0x6f, 0x9c, 0xc6, 0x48, 0x33, 0x66, 0xee,
-//         0x83, 0x38, 0x57, 0x94, 0x34, 0xe7, 0xb3, 0xc0, 0xb0, 0x13, 0xb2, 0x4b, 0x10, 0x21, 0xa0,
-//         0x00, 0xcf, 0x90, 0xef, 0xf6, 0x7c, 0x05, 0x95, 0x4a, 0x2f, 0x19, 0x51, 0x97, 0x00, 0xa0,
-//         0xf5, 0x6e, 0x89, 0x1e, 0x56, 0xdf, 0xea, 0x6a, 0x6d, 0x4d, 0x6e, 0x83, 0xb8, 0x55, 0x79,
-//         0xb1, 0xde, 0xfa, 0x7f, 0x7d, 0x6c, 0xbf, 0x5a, 0x04, 0x64, 0x06, 0xe8, 0xeb, 0xcd, 0x01,
-//         0x64, 0x69, 0x70, 0xc6, 0xae, 0xed, 0xff, 0x71, 0x46, 0xfc, 0x51, 0xa7, 0x79, 0xe1, 0x21,
-//         0x0a, 0x44, 0x98, 0x2a, 0x72, 0xd5, 0x1a, 0xd6, 0xb0, 0xb1, 0xa3, 0x72, 0x22, 0xa6, 0xc3,
-//         0x0c, 0x97, 0x72, 0x68, 0x3f, 0x38, 0x94, 0x88, 0xe8, 0xb5, 0x37, 0xb5, 0x44, 0x7d, 0x58,
-//         0xac, 0x55, 0xd5, 0xc4, 0x34, 0xbb, 0x6c, 0x17, 0xda, 0x42, 0x1e, 0x4f, 0xd0, 0xb3, 0x04,
-//         0x6d, 0x40, 0xbe, 0x4a, 0xc7, 0x2e, 0xba, 0xab, 0xa4, 0xc9, 0x1b, 0xf5, 0xd9, 0x54, 0x5f,
-//         0xa0, 0xa2, 0xf8, 0x65, 0x54, 0xd7, 0x51, 0x43, 0x4d, 0x0b, 0xc9, 0x1c, 0x6a, 0xb4, 0x8e,
-//         0x4b, 0x15, 0x49, 0xa1, 0x38, 0x81, 0xa3, 0xf8, 0xe9, 0x6f, 0xbe, 0x60, 0xba, 0x74, 0x46,
-//         0xb9, 0xf6, 0xfb, 0xce, 0x81, 0xa3, 0x3c, 0x71, 0xf9, 0x47, 0xe3, 0x2a, 0xaa, 0xc3, 0xa9,
-//         0xcd, 0x3f, 0x4f, 0xca, 0xb2, 0xde, 0xc9, 0x42, 0x2d, 0xf9, 0xd7, 0x30, 0xa6, 0x4e, 0x97,
-//         0x82, 0xb2, 0x0d, 0x61, 0xe5, 0x5d, 0xc2, 0x7a, 0xf9, 0x7e, 0xe7, 0x33, 0x5b, 0xcd, 0xd3,
-//         0x76, 0x00, 0x43, 0x7b, 0x78, 0xe1, 0x92, 0x64, 0x0c, 0x93, 0xf5, 0xa8, 0x76, 0x07, 0x5b,
-//         0xb3, 0x7d, 0x7c, 0x76, 0x26, 0xeb, 0x02, 0x61, 0x8c, 0xf2, 0xf4, 0x19, 0x1c, 0x4c, 0x88,
-//         0x41, 0xf8, 0x53, 0x00, 0xa2, 0x2c, 0xce, 0xe0, 0x50, 0x74, 0x94, 0xfd, 0xd9, 0x2c, 0xc5,
-//         0x67, 0xc6, 0x71, 0x8e, 0xfa, 0xb7, 0x1e, 0x3d, 0x86, 0xbd, 0x33, 0x81, 0x96, 0x41, 0x1f,
-//         0x2c, 0x6f, 0x16, 0xa4, 0x67, 0x08, 0x78, 0xab, 0x48, 0x7d, 0xe9, 0xc1, 0xff, 0x01, 0x3f,
-//         0x9b, 0x25, 0xc6, 0x24, 0x5b, 0x4d, 0x63, 0x8d, 0xf3, 0x45, 0x08, 0x82, 0xfc, 0x3e, 0x8a,
-//         0x59, 0x37, 0xec, 0x0f, 0x1d, 0xc2, 0x07, 0x57, 0x32, 0xa8, 0xba, 0x4f, 0x59, 0xf4, 0x5c,
-//         0x9f, 0xab, 0x96, 0xbc, 0xbf, 0x7c, 0xa8, 0x29, 0x2f, 0x80, 0x72, 0x52, 0x86, 0xb2, 0x1c,
-//         0x72, 0x95, 0xff, 0x45, 0x66, 0xdc, 0x11, 0xf0, 0xc2, 0x13, 0x46, 0x67, 0x9b, 0x9b, 0xeb,
-//         0xe8, 0xe0, 0x41, 0x75, 0x10, 0xfe, 0x38, 0x7b, 0xff, 0x74, 0xbd, 0xb4, 0x48, 0xeb, 0x20,
-//         0x48, 0x2c, 0x5b, 0x6d, 0xed, 0x9a, 0xb4, 0x88, 0xb5, 0xb9, 0xee, 0x64, 0x8b, 0x6e, 0xb6,
-//         0xd7, 0x42, 0xf0, 0x4a, 0x92, 0x07, 0x67, 0x5a, 0xdf, 0xbe, 0x24, 0x69, 0x18, 0xc6, 0x60,
-//         0x5f, 0x3e, 0x90, 0xc8, 0x7e, 0xb8, 0x42, 0x4c, 0x68, 0x90, 0x34, 0xf6, 0x95, 0xa4, 0x65,
-//         0xb7, 0xa0, 0x44, 0x0c, 0x2a, 0x36, 0x0b, 0x46, 0xee, 0xc4, 0xc5, 0xc6, 0x2a, 0xbd, 0x51,
-//         0x39, 0x5e, 0x26, 0x1b, 0x9d, 0x8f, 0x31, 0x02, 0xa5, 0x87, 0x01, 0xab, 0xef, 0xc6, 0xcb,
-//         0xf3, 0xf7, 0xea, 0xf1, 0xe0, 0x7a, 0xc7, 0xc1, 0xdb, 0x82, 0x94, 0xdd, 0x40, 0x42, 0x28,
-//         0x1a, 0xa7, 0x66, 0xa0, 0x70, 0xe7, 0xb0, 0x52, 0xa0, 0x97, 0xde, 0xfa, 0x91, 0x85, 0x1c,
-//         0x62, 0xd1, 0x6f, 0x23, 0x73, 0x26, 0x87, 0x62, 0xa8, 0x26, 0x85, 0x12, 0x25, 0x0d, 0x77,
-//         0x25, 0xbc, 0x56, 0xfe, 0x95, 0xc2, 0x40, 0xa1, 0x44, 0x3b, 0x51, 0xec, 0x4b, 0xb5, 0xbc,
-//         0x84, 0xfa, 0xb0, 0xb8, 0x00, 0x00, 0xa2, 0x0c, 0x8b, 0x06, 0x61, 0x94, 0x64, 0x0c, 0x26,
-//         0xc6, 0x3d, 0x63, 0x39, 0x00, 0x32, 0xa8, 0x32, 0x71, 0xa8, 0x38, 0xc2, 0x0c, 0xbf, 0x34,
-//         0xd5, 0x5a, 0x02, 0xa0, 0x25, 0x2a, 0x8d, 0x0c, 0x61, 0x11, 0x27, 0x6c, 0x78, 0x1c, 0xb9,
-//         0x15, 0x9d, 0x18, 0x63, 0x17, 0xc7, 0xb5, 0x90, 0x6e, 0x30, 0x25, 0x25, 0xab, 0xda, 0x80,
-//         0xde, 0x42, 0x15, 0x69, 0xeb, 0xd9, 0xf9, 0x9e, 0x06, 0x4d, 0xed, 0x73, 0x73, 0xdb, 0x5b,
-//         0x5e, 0x26, 0xc3, 0xa1, 0xf8, 0x6b, 0x2b, 0x4c, 0xb7, 0xda, 0x1c, 0xad, 0xcc, 0xc8, 0xe9,
-//         0xe0, 0x8a, 0xd0, 0x83, 0x2f, 0x61, 0x33, 0xe1, 0xd0, 0xbe, 0x30, 0xd6, 0x3d, 0x0f, 0x38,
-//         0xd0, 0x0d, 0xdf, 0x28, 0xb6, 0x85, 0x3c, 0x15, 0xa8, 0x40, 0x39, 0x6b, 0x9b, 0x39, 0xe7,
-//         0xa7, 0x73, 0x4d, 0x30, 0xc8, 0x9f, 0x65, 0x41, 0x16, 0x09, 0x50, 0xf7, 0x53, 0x41, 0xaa,
-//         0x89, 0xec, 0xc6, 0x6b, 0x83, 0xc2, 0x2f, 0x9b, 0x8d, 0x6d, 0xf4, 0x54, 0xa4, 0xe9, 0x29,
-//         0x4b, 0xb6, 0xf2, 0x2a, 0xb1, 0x13, 0x97, 0x93, 0x78, 0x1c, 0x74, 0x34, 0xee, 0xe2, 0x65,
-//         0x7d, 0x3c, 0xd6, 0x0c, 0x64, 0x00, 0x30, 0xb4, 0x83, 0xcf, 0x81, 0xaf, 0x8d, 0x94, 0x42,
-//         0x68, 0xa9, 0x7b, 0x46, 0x82, 0x35, 0x47, 0xee, 0xbc, 0xe5, 0xf6, 0x74, 0xf3, 0x06, 0x04,
-//         0xa3, 0xe4, 0xf9, 0x0c, 0x70, 0x14, 0x3d, 0x00, 0x2b, 0xe5, 0x38, 0x59, 0x09, 0x56, 0xda,
-//         0x62, 0x29, 0x23, 0xe6, 0x84, 0x33, 0x18, 0x64, 0x6c, 0xec, 0xdd, 0x6a, 0xa6, 0x28, 0x6e,
-//         0xb9, 0xc5, 0x4a, 0x23, 0xe2, 0x21, 0x99, 0xff, 0xe4, 0x2b, 0x3d, 0x42, 0x92, 0x5f, 0xdc,
-//         0x74, 0x20, 0x3c, 0xdd, 0x60, 0xa3, 0x26, 0x7a, 0xc5, 0x59, 0xed, 0xda, 0xf7, 0xb7, 0xec,
-//         0x04, 0x0c, 0xb7, 0x05, 0x2c, 0x00, 0x00, 0x00, 0x00, 0x80, 0xc9, 0x00, 0x1f, 0xbf, 0x19,
-//         0x3e, 0x1b, 0x40, 0x6e, 0x0b, 0x09, 0xbc, 0x28, 0xa0, 0x01, 0xb1, 0x25, 0xe1, 0xee, 0xa8,
-//         0xd3, 0x1e, 0x4d, 0x4b, 0xfc, 0x7c, 0xa8, 0xaa, 0x2a, 0x46, 0xbd, 0xc1, 0x4b, 0xda, 0x7a,
-//         0xc7, 0x4a, 0xbb, 0x2c, 0x02, 0xf9, 0xd3, 0xf8, 0x40, 0xd1, 0x8c, 0xa2, 0x08, 0xa6, 0x88,
-//         0x5a, 0x41, 0xa9, 0x9c, 0xca, 0x9e, 0x9c, 0x50, 0x32, 0x56, 0xc3, 0x51, 0x8e, 0x1f, 0x23,
-//         0xab, 0xd7, 0x71, 0xf2, 0x0f, 0x76, 0xe3, 0xa6, 0xa9, 0x6b, 0x54, 0xb3, 0x24, 0xe1, 0xbe,
-//         0x84, 0x97, 0xef, 0xa4, 0xe9, 0x4e, 0x49, 0x18, 0x34, 0x52, 0x09, 0xe7, 0x05, 0x2e, 0xd7,
-//         0x96, 0xe1, 0x3d, 0xab, 0x11, 0xf6, 0xae, 0xa8, 0x59, 0x01, 0x92, 0xd4, 0xec, 0xac, 0xcc,
-//         0xe4, 0x65, 0xcc, 0xe0, 0xb1, 0xd4, 0x9a, 0x1e, 0x7f, 0xca, 0x99, 0x14, 0xc6, 0x99, 0xf9,
-//         0x08, 0x7f, 0xfd, 0x21, 0x9f, 0xd4, 0xfa, 0x42, 0xfd, 0x56, 0x97, 0xed, 0xd9, 0x2a, 0xf2,
-//         0x98, 0x91, 0xfe, 0xf9, 0x33, 0xe1, 0x6a, 0x4a, 0x16, 0xd0, 0x6e, 0x6f, 0x88, 0x9f, 0x76,
-//         0x5e, 0x5f, 0x09, 0xe5, 0x84, 0x49, 0xd1, 0x7e, 0x85, 0x7f, 0xf6, 0x5f, 0x6b, 0x89, 0x7c,
-//         0xf0, 0xcd, 0xd4, 0x2d, 0x16, 0xd9, 0x96, 0xf2, 0xb7, 0x79, 0x2a, 0x0d, 0x48, 0x01, 0x67,
-//         0x42, 0x87, 0xfa, 0x24, 0xf9, 0x6f, 0x79, 0xe4, 0x75, 0xbd, 0xde, 0xe3, 0xa6, 0x34, 0x30,
-//         0xa0, 0x6a, 0xd9, 0xac, 0x7e, 0x7a, 0xcc, 0x0a, 0xb2, 0x6c, 0xd0, 0x88, 0x12, 0xde, 0x66,
-//         0x8e, 0x16, 0x40, 0x8b, 0x80, 0xe7, 0xdc, 0x3b, 0xb9, 0x51, 0xb1, 0x31, 0x21, 0x96, 0xe2,
-//         0x85, 0x16, 0x81, 0xc7, 0xb1, 0x17, 0x97, 0x89, 0x5d, 0x0c, 0xf4, 0xe4, 0xcf, 0x96, 0x84,
-//         0x90, 0x00, 0x46, 0x17, 0xa7, 0x3a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8e,
-//         0x1d, 0x06, 0x20, 0x00, 0x83, 0x92, 0x30, 0x37, 0xc9, 0x13, 0x31, 0x81, 0xa5, 0x84, 0xc4,
-//         0x81, 0x85, 0x5d, 0x06, 0xac, 0x40, 0x56, 0x8d, 0x33, 0xb7, 0x2a, 0x04, 0x29, 0x72, 0x8f,
-//         0x70, 0x75, 0xa6, 0x10, 0x66, 0xed, 0xb4, 0xc5, 0x24, 0x3e, 0x36, 0x8f, 0xd4, 0xdd, 0x30,
-//         0x4a, 0x08, 0xff, 0x90, 0x1a, 0xd1, 0x0f, 0x16, 0x21, 0x64, 0x96, 0x64, 0x58, 0x89, 0xc3,
-//         0x76, 0x00, 0x32, 0xad, 0x08, 0xc4, 0xc8, 0x80, 0x0d, 0x41, 0x22, 0xf7, 0xfe, 0x9c, 0x8c,
-//         0x86, 0x90, 0x67, 0x92, 0x89, 0xac, 0xcc, 0x8f, 0x46, 0xd7, 0xf5, 0xf2, 0x20, 0xc6, 0xfd,
-//         0x5d, 0x88, 0xee, 0xbc, 0x3c, 0x4d, 0x15, 0xac, 0x4b, 0x15, 0x30, 0x47, 0xe8, 0x9a, 0xc3,
-//         0x63, 0xe1, 0xcb, 0x04, 0x17, 0x96, 0x9c, 0xe6, 0x27, 0x19, 0xc0, 0x30, 0x93, 0xf3, 0xd1,
-//         0x8e, 0x0d, 0xc8, 0x99, 0x0e, 0x86, 0xc2, 0x9e, 0x24, 0xf4, 0x32, 0x3a, 0xfe, 0x6f, 0xf2,
-//         0xf9, 0xec, 0x3c, 0x40, 0xf8, 0x60, 0x52, 0xce, 0x89, 0x6c, 0x02, 0xbf, 0xfa, 0x93, 0xc0,
-//         0xd2, 0xb3, 0xc4, 0x46, 0xce, 0xaf, 0x58, 0xba, 0x84, 0x03, 0x11, 0x50, 0x1b, 0x54, 0xcd,
-//         0x61, 0x80, 0xa6, 0x08, 0xb2, 0x40, 0x3f, 0x4a, 0x03, 0x0e, 0x48, 0x26, 0x8a, 0xb0, 0x13,
-//         0x30, 0xff, 0x12, 0x80, 0x97, 0xf7, 0xef, 0x33, 0xbf, 0x75, 0x2c, 0x38, 0x69, 0x78, 0x34,
-//         0x16, 0xdf, 0x01, 0x41, 0x37, 0xb5, 0x82, 0x41, 0x24, 0x2b, 0x7e, 0x90, 0xeb, 0xb7, 0x93,
-//         0xf9, 0x54, 0xd6, 0x92, 0x97, 0x8f, 0x66, 0x60, 0xbc, 0xd4, 0x4e, 0xf7, 0x7a, 0xa8, 0x89,
-//         0xd8, 0x61, 0x86, 0x67, 0x2b, 0x05, 0x63, 0x96, 0xe0, 0x64, 0x04, 0x98, 0x38, 0x66, 0x83,
-//         0x9a, 0x79, 0x37, 0xfc, 0x7f, 0xca, 0xbf, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb0, 0x09, 0x80, 0xac, 0x42, 0x21, 0x42,
-//         0x21, 0x86, 0x9a, 0x82, 0x05, 0x0c, 0x36, 0x11, 0x80, 0xa5, 0x40, 0x41, 0x36, 0x11, 0x13,
-//         0x49, 0x89, 0x87, 0x47, 0xe1, 0x33, 0x03, 0x9f, 0x25, 0xa7, 0x98, 0xef, 0x03, 0xb2, 0x0f,
-//         0xc2, 0xb2, 0x28, 0xc6, 0xd8, 0x84, 0xb5, 0x48, 0x18, 0x8a, 0xd4, 0x44, 0x8b, 0x63, 0x01,
-//         0xef, 0x5f, 0x41, 0xf7, 0x81, 0x5f, 0x82, 0xd9, 0xf2, 0x52, 0x0d, 0x91, 0x45, 0xb1, 0x4c,
-//         0x82, 0x50, 0x8e, 0xa9, 0x1b, 0x2e, 0x4a, 0x1b, 0x91, 0xb9, 0x16, 0x58, 0xae, 0x05, 0x76,
-//         0xa2, 0xe4, 0x7e, 0xd6, 0x08, 0x02, 0x50, 0x56, 0x14, 0x8b, 0x12, 0x19, 0x0b, 0x48, 0x6b,
-//         0xd0, 0x83, 0x29, 0xa5, 0xa3, 0xf1, 0x02, 0x5e, 0x16, 0xdd, 0xb2, 0xa3, 0x55, 0x39, 0x3b,
-//         0x69, 0xe6, 0x14, 0x28, 0xd6, 0x7e, 0x0f, 0x9b, 0x85, 0xdf, 0xb7, 0x60, 0x48, 0x89, 0x5a,
-//         0xcb, 0x01, 0xa5, 0x68, 0x3e, 0x99, 0x35, 0xc1, 0x46, 0xd7, 0x3d, 0x8f, 0xf4, 0x9c, 0x4d,
-//         0xf5, 0xc7, 0xd4, 0x43, 0xa3, 0x8f, 0x15, 0xed, 0x00, 0xf3, 0xf2, 0x5e, 0xbd, 0x67, 0x09,
-//         0x22, 0xa3, 0x91, 0x77, 0x89, 0x8b, 0x18, 0x84, 0xb6, 0x0b, 0xa9, 0xd2, 0x98, 0xc1, 0x55,
-//         0x77, 0x40, 0xbf, 0x39, 0xf6, 0xcd, 0x8a, 0x5e, 0xb7, 0xaf, 0x99, 0x34, 0xd8, 0xcb, 0x96,
-//         0x6f, 0xd2, 0x8c, 0xab, 0xff, 0x3d, 0x7e, 0x27, 0x44, 0xe7, 0x35, 0xa2, 0xbf, 0x86, 0x57,
-//         0x75, 0xa7, 0xdf, 0xc2, 0xce, 0x6a, 0x6c, 0x9d, 0xd6, 0xb9, 0xbb, 0xcc, 0x7c, 0x6b, 0xf6,
-//         0x59, 0x0d, 0x53, 0xa6, 0x84, 0x1c, 0xa1, 0xcd, 0xe7, 0x14, 0x74, 0x72, 0x29, 0x47, 0x44,
-//         0x62, 0x92, 0xdc, 0x34, 0xfa, 0x6b, 0x24, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x43, 0x1c, 0x04, 0x70,
-//         0xa7, 0x27, 0x22, 0x40, 0x16, 0x81, 0x4c, 0x83, 0x84, 0x52, 0x94, 0x2a, 0x49, 0x23, 0xc0,
-//         0x50, 0x75, 0x39, 0x45, 0x02, 0x41, 0x24, 0x92, 0xc8, 0x31, 0x62, 0x05, 0xfd, 0x2e, 0xc1,
-//         0xa9, 0x3a, 0x0c, 0x17, 0xa3, 0x00, 0xa0, 0xd5, 0x17, 0x02, 0x8f, 0x49, 0x65, 0x4d, 0xa0,
-//         0x86, 0x6d, 0x26, 0x48, 0xe6, 0xc1, 0x10, 0xbc, 0x4c, 0x05, 0xd1, 0xa2, 0x32, 0xdb, 0xc6,
-//         0xc2, 0x8d, 0x13, 0x69, 0x06, 0x44, 0x37, 0x05, 0x7a, 0xce, 0x65, 0x47, 0x1b, 0xa4, 0x95,
-//         0x4e, 0x3f, 0xb4, 0x00, 0x90, 0x5c, 0xb0, 0x41, 0x11, 0x77, 0x99, 0x9c, 0x5a, 0x72, 0xbb,
-//         0xb9, 0xe5, 0xbe, 0x84, 0x8d, 0x7f, 0x9e, 0x03, 0x4e, 0x4a, 0xed, 0x66, 0xaf, 0x68, 0x0d,
-//         0x5b, 0x3c, 0x88, 0x49, 0xf6, 0x0c, 0x91, 0x40, 0x90, 0x15, 0x07, 0x34, 0x78, 0xe7, 0x5b,
-//         0x30, 0x66, 0x26, 0xb0, 0x9e, 0x50, 0x2d, 0x8c, 0x49, 0x88, 0x50, 0x47, 0x8b, 0x95, 0xc9,
-//         0xcc, 0x86, 0x2f, 0x0f, 0x30, 0x30, 0xf2, 0x83, 0x70, 0x7d, 0x7e, 0x36, 0x46, 0x68, 0x9b,
-//         0x82, 0x90, 0x72, 0x1a, 0xa5, 0xba, 0x93, 0xdb, 0x07, 0x20, 0x03, 0xc2, 0x19, 0x38, 0xac,
-//         0x73, 0xa7, 0x48, 0x71, 0x0a, 0x33, 0xa8, 0xd8, 0x13, 0xc2, 0x99, 0x40, 0x25, 0x84, 0x82,
-//         0x4c, 0x8f, 0x06, 0x6b, 0xb9, 0x4d, 0x24, 0xd4, 0x72, 0x1a, 0x2c, 0x33, 0x1e, 0x09, 0xc6,
-//         0xfe, 0x2a, 0x55, 0x00, 0x81, 0xab, 0x52, 0x7f, 0x25, 0x88, 0xa0, 0xb5, 0x55, 0x21, 0x0f,
-//         0xd9, 0xb0, 0x95, 0x14, 0xca, 0x75, 0x9d, 0x56, 0x46, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1d, 0xa4,
-//         0x8a, 0x6a, 0xe1, 0xc8, 0x42, 0x18, 0x02, 0x10, 0x10, 0x02, 0x80, 0x8f, 0x41, 0x69, 0x05,
-//         0x50, 0x11, 0x65, 0x84, 0x10, 0x17, 0x90, 0x00, 0x48, 0x1e, 0x29, 0x8a, 0x0a, 0x61, 0x2c,
-//         0x9d, 0xa2, 0x30, 0x02, 0x80, 0x42, 0x91, 0xb1, 0x5e, 0x0c, 0x94, 0x06, 0x36, 0xc1, 0x18,
-//         0x35, 0x04, 0x38, 0x0c, 0xa0, 0x19, 0x2d, 0x00, 0x3c, 0xa4, 0x08, 0x66, 0x36, 0x4e, 0x20,
-//         0x08, 0x22, 0x40, 0xbe, 0x5a, 0x0c, 0xa6, 0xc4, 0x33, 0x43, 0xf0, 0xbe, 0xba, 0x69, 0xff,
-//         0x05, 0xbb, 0x59, 0x49, 0x29, 0xa1, 0xe4, 0x20, 0xb5, 0x8b, 0x47, 0x83, 0x11, 0x1c, 0xf0,
-//         0x7f, 0xd1, 0x69, 0x01, 0xbc, 0xcb, 0x52, 0xf5, 0x9b, 0xf6, 0x82, 0x3d, 0x4c, 0x70, 0x80,
-//         0xa2, 0x22, 0x07, 0x57, 0xc7, 0x58, 0x96, 0x9d, 0x59, 0x0f, 0x44, 0x80, 0xa0, 0xd0, 0x4b,
-//         0x59, 0x50, 0xf3, 0xc4, 0x47, 0x2b, 0xe4, 0x49, 0xc2, 0xd2, 0x50, 0xc6, 0xdd, 0x3f, 0xe0,
-//         0x98, 0x78, 0x00, 0x10, 0x02, 0x3e, 0x23, 0x11, 0xe0, 0x30, 0xde, 0xc0, 0x97, 0xca, 0x8b,
-//         0xd2, 0xd8, 0xeb, 0x62, 0x0e, 0x56, 0x71, 0x0c, 0xce, 0x5b, 0xd8, 0x18, 0x20, 0xea, 0x32,
-//         0x1e, 0x19, 0x85, 0x5a, 0x78, 0x31, 0xe6, 0x53, 0xfe, 0x78, 0xb6, 0x4a, 0xe3, 0x6c, 0x92,
-//         0x4c, 0xc5, 0x02, 0xc0, 0xc5, 0x26, 0xd7, 0x05, 0xc4, 0x93, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-//         0x00, 0x00, 0x00, 0x89, 0x98, 0xc1, 0x30, 0x50, 0x09, 0x80, 0x70, 0x02, 0x23, 0x73, 0x90,
-//         0x30, 0x74, 0x09, 0x88, 0x45, 0x05, 0x02, 0x34, 0x40, 0x02, 0xcd, 0x45, 0x80, 0x9e, 0x60,
-//         0xa3, 0x02, 0x90, 0x09, 0xa0, 0x0d, 0x95, 0x20, 0x64, 0x21, 0x91, 0x38, 0x0e, 0x49, 0x69,
-//         0x7a, 0x08, 0x68, 0x93, 0x4d, 0x33, 0x76, 0x09, 0xdd, 0xca, 0x2a, 0x0d, 0x52, 0x1e, 0x0c,
-//         0xa0, 0x60, 0x12, 0x70, 0x02, 0x02, 0x20, 0x70, 0xa1, 0x81, 0x10, 0x23, 0xb0, 0x8a, 0x90,
-//         0x14, 0x04, 0x64, 0xc8, 0x01, 0x91, 0x00, 0x80, 0x31, 0x0a, 0x68, 0x63, 0xa2, 0x76, 0x19,
-//         0x59, 0x6a, 0xb0, 0x25, 0x8b, 0x02, 0x8b, 0x2c, 0xa4, 0xe0, 0x53, 0x48, 0xa3, 0x4a, 0x41,
-//         0x81, 0x30, 0x02, 0x42, 0x40, 0xd8, 0x04, 0x08, 0xc0, 0x02, 0xf6, 0xc0, 0x28, 0x49, 0xc1,
-//         0x33, 0x49, 0xc0, 0xb8, 0x0a, 0x33, 0x10, 0x90, 0x84, 0x50, 0x89, 0x07, 0x6e, 0xda, 0xf4,
-//         0x77, 0x38, 0x71, 0x79, 0x36, 0xc3, 0xd8, 0x4e, 0xa9, 0x6d, 0x66, 0xe6, 0xa4, 0x46, 0x57,
-//         0xea, 0xe4, 0x8d, 0x7d, 0x40, 0x08, 0x00, 0xe6, 0x75, 0x0d, 0x87, 0xc8, 0x51, 0xb0, 0x78,
-//         0xf1, 0x4a, 0x09, 0x7a, 0x1a, 0xae, 0x25, 0xb7, 0x44, 0xfc, 0xd2, 0xbe, 0xfd, 0x3c, 0x43,
-//         0x8c, 0x69, 0x41, 0xf7, 0x1b, 0xab, 0x9d, 0xb3, 0x83, 0xae, 0x27, 0x63, 0x23, 0x61, 0x36,
-//         0x58, 0xc6, 0x48, 0x8d, 0x01, 0xf2, 0xde, 0xca, 0xbc, 0x78, 0xa7, 0x65, 0xc7, 0x9e, 0x06,
-//         0x11, 0x2b, 0x12, 0xca, 0x5c, 0xc7, 0x39, 0x02, 0xdf, 0x39, 0x9f, 0x9e, 0xc2, 0x7a, 0xc3,
-//         0xf1, 0xe6, 0x22, 0x2a, 0x4e, 0x6b, 0x05, 0xec, 0x60, 0x4c, 0x42, 0xf8, 0xf4, 0x96, 0x36,
-//         0x74, 0xd7, 0xc6, 0xb5, 0x94, 0x11, 0x98, 0x11, 0x44, 0xc5, 0x7a, 0x1f, 0xf1, 0x40, 0x7c,
-//         0x39, 0xd9, 0x9a, 0xe8, 0xbe, 0xb1, 0x2b, 0x00, 0x24, 0x67, 0x96, 0x0c, 0x0d, 0x20, 0x2f,
-//         0xd2, 0xd7, 0x9a, 0xad, 0x3f, 0xdb, 0x81, 0xa1, 0x9c, 0x9c, 0xd7, 0x80, 0xb7, 0xc0, 0xff,
-//         0x97, 0xda, 0xb2, 0x30, 0x75, 0xb4, 0xfd, 0xed, 0x00, 0xbc, 0xb0, 0x8d, 0x23, 0x70, 0xcc,
-//         0xda, 0xbf, 0xa0, 0xbe, 0x45, 0x30, 0x83, 0x3c, 0x87, 0xb6, 0xbf, 0x8d, 0x76, 0x75, 0x83,
-//         0xbe, 0x35, 0x4e, 0x5c, 0x1c, 0x4d, 0x37, 0xdd, 0xa1, 0x65, 0xb7, 0x5a, 0xf6, 0x05, 0x61,
-//         0x30, 0xca, 0x7c, 0x41, 0x9d, 0x4c, 0x61, 0xde, 0xc5, 0x10, 0x6c, 0x33, 0x37, 0x30, 0x46,
-//         0xdf, 0x00, 0xd6, 0x2d, 0xab, 0x0d, 0x7d, 0x5c, 0x0f, 0x1b, 0xd2, 0x55, 0x20, 0xd0, 0x5a,
-//         0x23, 0xbd, 0x71, 0x14, 0x0e, 0x9c, 0x4c, 0xc1, 0xa8, 0x24, 0xa4, 0x42, 0x78, 0x38, 0xd3,
-//         0xf8, 0x3b, 0x1a, 0xbe, 0xe0, 0xee, 0xf7, 0xa3, 0x89, 0x14, 0xeb, 0x63, 0xba, 0x4f, 0xd1,
-//         0xa1, 0xc6, 0xba, 0x3d, 0x83, 0x9f, 0x49, 0xd3, 0x6c, 0x0d, 0x68, 0xda, 0x49, 0xd4, 0xd6,
-//         0x01, 0x84, 0x08, 0xec, 0xb8, 0x73, 0x30, 0x94, 0x52, 0x65, 0x2a, 0x06, 0xb1, 0x21, 0xe8,
-//         0x9a, 0xe9, 0x26, 0x5f, 0xbd, 0xac, 0xb0, 0x3c, 0x39, 0x62, 0x98, 0x24, 0x64, 0x14, 0xf8,
-//         0x99, 0xed, 0xf9, 0x04, 0x91, 0x05, 0x95, 0xdb, 0x2d, 0x66, 0x06, 0x0f, 0x86, 0x1a, 0x6c,
-//         0x79, 0xea, 0x90, 0x5e, 0xce, 0x47, 0x17, 0x66, 0xc1, 0x32, 0x48, 0x46, 0xa9, 0xf8, 0x26,
-//         0x85, 0xe0, 0x4a, 0x4b, 0x72, 0x6d, 0x43, 0xad, 0xb3, 0x88, 0xf7, 0x5d, 0xc5, 0x0a, 0xc7,
-//         0x4c, 0x7f, 0x9a, 0xa5, 0x05, 0xb7, 0xdd, 0x99, 0xdb, 0x05, 0x2b, 0x87, 0x2c, 0x9f, 0xcf,
-//         0xb7, 0x2b, 0x4e, 0x65, 0x64, 0x92, 0xf4, 0x24, 0x9d, 0xec, 0x6a, 0x99, 0x0d, 0x5b, 0xbb,
-//         0x6a, 0x61, 0xa5, 0x51, 0x38, 0xa9, 0x6b, 0xdb, 0xd3, 0x2d, 0xfb, 0x04, 0x7e, 0xf2, 0x2e,
-//         0xe4, 0x51, 0x56, 0x9b, 0x18, 0xe8, 0x1d, 0x85, 0x89, 0x7d, 0xd0, 0xce, 0xea, 0xb5, 0x81,
-//         0x9f, 0x75, 0x9b, 0xda, 0xcf, 0xd1, 0x84, 0xca, 0x82, 0x55, 0xc9, 0x9e, 0x41, 0xf3, 0x2c,
-//         0x6a, 0xa9, 0xb5, 0x08, 0xa0, 0x5f, 0x2e, 0x62, 0xb1, 0x17, 0x58, 0x96, 0xf3, 0x18, 0x71,
-//         0x0a, 0xcc, 0x2b, 0xd8, 0x58, 0xe3, 0x84, 0x16, 0x73, 0xbe, 0x5d, 0x25, 0x05, 0x9a, 0x95,
-//         0xd0, 0x41, 0x8e, 0xf3, 0xa6, 0x9a, 0x9e, 0xf5, 0xc0, 0x3d, 0x5a, 0x74, 0x42, 0xa9, 0x64,
-//         0x48, 0x6a, 0xe1, 0x80, 0x43, 0xba, 0x01, 0x60, 0x54, 0x82, 0x46, 0x3b, 0xff, 0xa2, 0x97,
-//         0x03, 0x20, 0x12, 0x99, 0xbf, 0x45, 0x40, 0xbe, 0xbf, 0xe3, 0xfd, 0x0c, 0x3d, 0xf8, 0xdf,
-//         0x36, 0x4a, 0xa0, 0x05, 0xb1, 0x39, 0x98, 0x3e, 0x65, 0xe9, 0x87, 0x21, 0x45, 0xbc, 0x0c,
-//         0xf0, 0xc4, 0x3f, 0x3f, 0x3f, 0x34, 0x67, 0x6d, 0xeb, 0x32, 0x6a, 0x77, 0x3b, 0xbf, 0x5b,
-//         0xe8, 0xf6, 0x2e, 0x31, 0x06, 0xc4, 0x6c, 0xd5, 0x91, 0x32, 0x64, 0x4d, 0x71, 0xd2, 0x24,
-//         0xbd, 0x4f, 0xbc, 0x88, 0xdd, 0x43, 0x70, 0x53, 0x80, 0x37, 0x0d, 0x73, 0x8e, 0x60, 0xe7,
-//         0x42, 0x42, 0x2f, 0x0d, 0x34, 0x01, 0x5d, 0xab, 0x98, 0xa4, 0xea, 0xc4, 0xe3, 0x67, 0xc3,
-//         0x4c, 0x47, 0x38, 0x5e, 0x13, 0xf3, 0xf1, 0xca, 0x64, 0x66, 0xb5, 0x43, 0xec, 0x75, 0xcb,
-//         0x13, 0x28, 0x19, 0x0e, 0x72, 0xe9, 0x23, 0x8f, 0x06, 0x7e, 0xd5, 0x19, 0xb8, 0xbe, 0x39,
-//         0xde, 0x1c, 0xaf, 0xf1, 0xb9, 0x7c, 0x69, 0x62, 0x66, 0x55, 0x79, 0xd2, 0xe7, 0x8d, 0xab,
-//         0xac, 0x57, 0x60, 0xe7, 0xbb, 0x9e, 0xaa, 0x0d, 0xc8, 0xe8, 0xfa, 0x80, 0x8e, 0x07, 0x86,
-//         0x7f, 0x34, 0x0b, 0xc8, 0xd7, 0x6e, 0x27, 0xa1, 0x15, 0x39, 0x3c, 0xc7, 0xcd, 0x42, 0x2b,
-//         0xcb, 0x1f, 0x58, 0x97, 0x7f, 0xd8, 0xff, 0x82, 0x17, 0x04, 0x1c, 0xd7, 0x75, 0xf0, 0x2a,
-//         0xea, 0x9b, 0x81, 0xf2, 0x9d, 0xf9, 0xe8, 0xfe, 0x5f, 0x48, 0x9b, 0x6c, 0x2d, 0xbe, 0x5a,
-//         0x67, 0x72, 0x19, 0xfd, 0x94, 0xd0, 0x66, 0x1f, 0xaa, 0x35, 0x4e, 0x6d, 0xd6, 0x46, 0x70,
-//         0x85, 0x07, 0x61, 0x8c, 0xe2, 0x18, 0xac, 0x49, 0xf8, 0x47, 0x7e, 0x46, 0x9f, 0xb8, 0x7f,
-//         0xd8, 0x90, 0xf8, 0x0d, 0x1b, 0x60, 0xbd, 0xe7, 0xb4, 0x99, 0x70, 0xf8, 0xc0, 0xa7, 0x52,
-//         0xb4, 0xa1, 0x68, 0xeb, 0xbc, 0xb2, 0xe1, 0x84, 0xc7, 0x84, 0x6c, 0xf3, 0x51, 0xcf, 0xed,
-//         0x46, 0x71, 0xa5, 0x3b, 0xcf, 0xf8, 0x0c, 0x08, 0x8a, 0x0d, 0x5c, 0x78, 0xd7, 0x33, 0x99,
-//         0x84, 0x37, 0x68, 0xab, 0x1b, 0xf0, 0x63, 0x07, 0x1c, 0x3f, 0x5b, 0xb3, 0xd3, 0x12, 0x3f,
-//         0xaa, 0x78, 0x37, 0xab, 0x93, 0x92, 0xea, 0xe4, 0xd5, 0x47, 0xe2, 0x27, 0x3b, 0x65, 0xcd,
-//         0x17, 0x49, 0x51, 0x56, 0x34, 0x0b, 0xf8, 0x73, 0xe9, 0xcf, 0x8c, 0x22, 0x2f, 0x08, 0x8a,
-//         0xf5, 0x6c, 0x12, 0x74, 0xf1, 0xe1, 0x45, 0xc5, 0x2f, 0x70, 0xa4, 0x99, 0x88, 0xdb, 0x8e,
-//         0x0f, 0x12, 0xa8, 0x9f, 0x42, 0xea, 0xab, 0xf1, 0xd1, 0xdb, 0x8e, 0xe8, 0xbb, 0x54, 0xc4,
-//         0xf0, 0x94, 0x43, 0x18, 0x71, 0xf2, 0x58, 0x8f, 0xb0, 0x4f, 0x9f, 0x42, 0x97, 0xf6, 0x89,
-//         0xd6, 0xfa, 0xcf, 0x58, 0x28, 0x42, 0x9a, 0xed, 0xdc, 0x25, 0xe3, 0xdd, 0x3e, 0x51, 0x09,
-//         0xad, 0x28, 0x75, 0xbd, 0x2d, 0x29, 0xf7, 0x3b, 0xfb, 0x60, 0x28, 0xe7, 0xfd, 0x0a, 0xca,
-//         0x7d, 0x6c, 0x93, 0x81, 0xdc, 0x90, 0x5d, 0x39, 0x92, 0xbe, 0xf2, 0x98, 0xb5, 0x8b, 0x34,
-//         0xb6, 0x90, 0x36, 0x3d, 0x95, 0x94, 0xd4, 0xb7, 0x43, 0xc4, 0xcc, 0x69, 0x14, 0xe8, 0xf3,
-//         0x23, 0xb2, 0x63, 0x47, 0x4e, 0x7f, 0x8f, 0xea, 0x16, 0x25, 0x30, 0x87, 0x69, 0x09, 0x4a,
-//         0x55, 0x20, 0x74, 0x94, 0xe2, 0xd3, 0x28, 0x82, 0xd0, 0x44, 0xbf, 0xfe, 0x39, 0x3b, 0xbe,
-//         0x4b, 0x0b, 0x52, 0xf6, 0xe8, 0x7a, 0xdb, 0x20, 0x36, 0x48, 0x2b, 0x06, 0xbd, 0x2b, 0xc7,
-//         0xc2, 0x7c, 0xf6, 0x81, 0x45, 0x03, 0x58, 0x64, 0x28, 0x6f, 0x32, 0x7f, 0x71, 0xdc, 0x43,
-//         0x40, 0xb5, 0xd9, 0x30, 0x69, 0x9d, 0x66, 0x67, 0xcb, 0x0e, 0xdc, 0xc4, 0x5b, 0x49, 0x99,
-//         0x97, 0x35, 0x8c, 0xd5, 0xd3, 0x62, 0x7e, 0xca, 0xd6, 0x83, 0x8d, 0xbd, 0x7f, 0x45, 0x08,
-//         0x45, 0xda, 0x1f, 0x7f, 0xa3, 0x82, 0x82, 0x43, 0x24, 0xe2, 0x20, 0xca, 0xd0, 0xf5, 0x27,
-//         0x24, 0xd2, 0x05, 0x0b, 0x1c, 0xe4, 0x37, 0x40, 0xa5, 0x52, 0x9e, 0x3d, 0x72, 0x6d, 0x06,
-//         0x91, 0x27, 0xa3, 0xf6, 0xc9, 0xee, 0xee, 0x9c, 0x6a, 0x1f, 0x8d, 0x63, 0x5d, 0xb1, 0xb4,
-//         0x0a, 0xe2, 0x06, 0x8b, 0x73, 0xe8, 0xa2, 0x39, 0x44, 0xa5, 0x28, 0xd2, 0x6a, 0x22, 0xf5,
-//         0xe7, 0x35, 0x0a, 0x85, 0x6d, 0xe2, 0x7e, 0x26, 0xe5, 0xf1, 0x5b, 0x69, 0xf6, 0xd0, 0xb4,
-//         0xdf, 0x8c, 0x91, 0xf4, 0x89, 0x8e, 0x37, 0xee, 0x62, 0x66, 0x66, 0x8b, 0xc5, 0xb3, 0x6b,
-//         0xae, 0x87, 0x70, 0x05, 0x08, 0x27, 0x85, 0xda, 0x86, 0xd0, 0x04, 0xa9, 0xf6, 0xe2, 0xb0,
-//         0x59, 0xa7, 0x3c, 0x88, 0xc6, 0x62, 0x62, 0xe7, 0x41, 0x30, 0xca, 0x72, 0x8b, 0xbb, 0x27,
-//         0x5e, 0xa5, 0xdc, 0x2b, 0x55, 0x60, 0x7a, 0x97, 0xa1, 0x4c, 0xb8, 0x8d, 0x98, 0xda, 0x1d,
-//         0x53, 0xa4, 0xb5, 0xef, 0xc8, 0xfb, 0xa0, 0x11, 0x2d, 0x55, 0x82, 0xc7, 0x2e, 0x72, 0xdf,
-//         0x45, 0x7d, 0x9a, 0x7b, 0xfb, 0xda, 0x9c, 0x13, 0xb0, 0x38, 0x7c, 0x52, 0xc6, 0x5e, 0x27,
-//         0xfb, 0xfc, 0xfb, 0xea, 0x8c, 0xe5, 0x39, 0x19, 0xec, 0x68, 0xf4, 0x92, 0x78, 0x9a, 0xb4,
-//         0x63, 0x02, 0xf9, 0xe7, 0x50, 0x8d, 0x40, 0xe2, 0x57, 0x94, 0x9d, 0xa5, 0xd9, 0xdc, 0xa0,
-//         0x43, 0xf3, 0x71, 0x54, 0x53, 0x64, 0x63, 0xd9, 0x9c, 0x2f, 0x30, 0xed, 0xcc, 0x65, 0x6b,
-//         0x53, 0xe3, 0xae, 0x9c, 0xba, 0x52, 0x80, 0x13, 0x95, 0x8f, 0xb4, 0x32, 0x4e, 0xa8, 0x0e,
-//         0x74, 0xd9, 0x3f, 0x27, 0x48, 0xdf, 0x75, 0x67, 0x35, 0x73, 0x28, 0xf8, 0x1b, 0x6a, 0x9f,
-//         0x9c, 0x77, 0xe9, 0x6e, 0x14, 0x99, 0x58, 0x5e, 0x48, 0xf7, 0x99, 0xaa, 0xe0, 0x3f, 0x61,
-//         0x98, 0x1c, 0xac, 0x31, 0x17, 0x29, 0x4c, 0xf2, 0xdf, 0x3f, 0xda, 0x84, 0xc5, 0xce, 0xbd,
-//         0x34, 0x69, 0xc5, 0x3f, 0x50, 0x64, 0x7e, 0xfb, 0x06, 0xe5, 0x1e, 0x5c, 0xcc, 0xb8, 0xa8,
-//         0x3c, 0x69, 0xe1, 0x58, 0x85, 0x95, 0xd7, 0xf9, 0x59, 0x96, 0x3f, 0x4b, 0x8f, 0x86, 0x04,
-//         0x50, 0x8a, 0x89, 0x12, 0x35, 0xda, 0xb2, 0xfe, 0x2d, 0xf1, 0x0b, 0xfa, 0x77, 0x97, 0x92,
-//         0x68, 0xdf, 0x7a, 0x58, 0xcd, 0xb0, 0x32, 0x8e, 0x46, 0xf1, 0xdd, 0x26, 0x9b, 0x57, 0x55,
-//         0x00, 0x2b, 0xab, 0xb7, 0xad, 0x3a, 0xc7, 0xa0, 0x7a, 0xdc, 0xa3, 0x85, 0xdc, 0xed, 0x69,
-//         0xe8, 0x30, 0x55, 0xa1, 0xfa, 0xdd, 0x8e, 0x97, 0x0b, 0xcc, 0x12, 0x4f, 0x6a, 0xd0, 0x62,
-//         0x9b, 0x98, 0xda, 0x28, 0x67, 0xf6, 0x13, 0xe9, 0x61, 0x2a, 0x98, 0xbe, 0x50, 0x73, 0x13,
-//         0xa1, 0xf3, 0xe4, 0x37, 0x91, 0x7f, 0xa9, 0xf8, 0xe2, 0x70, 0x7d, 0x09, 0xb0, 0x8e, 0x9e,
-//         0xdf, 0x88, 0x85, 0xac, 0x1b, 0x89, 0x14, 0x08, 0xe9, 0xa8, 0x9f, 0x41, 0xb4, 0x83, 0x26,
-//         0x83, 0x73, 0xa0, 0x04, 0x67, 0xac, 0x3a, 0x9b, 0x4a, 0xcf, 0x20, 0x40, 0xd5, 0xa2, 0xe9,
-//         0x98, 0xdb, 0x4b, 0x16, 0xdb, 0xf3, 0xa0, 0x1b, 0x6b, 0xaf, 0xcf, 0x28, 0x9b, 0xd4, 0x79,
-//         0x0c, 0x90, 0x64, 0xd0, 0x65, 0x98, 0x79, 0x25, 0xfe, 0x2f, 0x55, 0x47, 0x55, 0x85, 0x95,
-//         0x2d, 0xd6, 0xc9, 0x82, 0xcf, 0x5d, 0xf2, 0x07, 0x6d, 0x87, 0xdf, 0x32, 0xd0, 0xea, 0xe3,
-//         0xfe, 0x60, 0x2e, 0xf2, 0xbb, 0x15, 0x47, 0x87, 0x02, 0x57, 0x04, 0x77, 0x74, 0x22, 0xbe,
-//         0x62, 0x2b, 0x9d, 0x5c, 0xf9, 0x82, 0xe2, 0x55, 0xe9, 0x4d, 0xd7, 0xdf, 0x4e, 0xd3, 0x73,
-//         0x8e, 0x08, 0xf9, 0x75, 0x07, 0x74, 0x8a, 0x4f, 0x88, 0xbd, 0x3e, 0x9f, 0xa1, 0x13, 0x89,
-//         0xa6, 0xdb, 0x34, 0x3d, 0x78, 0xb4, 0xc8, 0xdf, 0xa4, 0x10, 0xd0, 0x4d, 0xb7, 0x45, 0xbd,
-//         0x47, 0xb5, 0x44, 0x27, 0x0f, 0xb8, 0x59, 0x5e, 0x1d, 0x98, 0x29, 0x9e, 0x8b, 0xf9, 0x45,
-//         0x25, 0xc0, 0x80, 0x0b, 0xe5, 0x31, 0x82, 0x06, 0xd1, 0x3c, 0xcd, 0xef, 0x13, 0x19, 0x73,
-//         0xce, 0xa3, 0x13, 0xcb, 0x97, 0x08, 0x76, 0x6c, 0xcb, 0xa4, 0x8d, 0x59, 0x56, 0xc4, 0x4c,
-//         0x3d, 0x7e, 0x5d, 0x72, 0x37, 0x8a, 0xbb, 0x19, 0xe9, 0x20, 0x52, 0xea, 0x24, 0x7b, 0x70,
-//         0x03, 0xb2, 0x89, 0x1a, 0xce, 0x4a, 0xb5, 0xe9, 0x1f, 0x34, 0x99, 0xf7, 0x5b, 0xc9, 0x6a,
-//         0xbc, 0x57, 0x31, 0xb5, 0x85, 0xd9, 0x6a, 0x9f, 0xb5, 0x08, 0x67, 0xf0, 0x71, 0x76, 0xf8,
-//         0x27, 0x77, 0x29, 0x3f, 0x89, 0x3f, 0xc0, 0x74, 0x0a, 0xc6, 0x54, 0x51, 0x84, 0x42, 0x85,
-//         0x33, 0x9f, 0x67, 0xc6, 0x4f, 0x2d, 0x97, 0x69, 0x0d, 0xdd, 0xe0, 0x70, 0xa7, 0x68, 0x09,
-//         0x1e, 0x9b, 0x0f, 0x81, 0x26, 0xb5, 0xfd, 0x7f, 0xaa, 0xcf, 0x1d, 0xb7, 0x4c, 0x09, 0xec,
-//         0x75, 0x3c, 0x49, 0xa1, 0x81, 0xc9, 0xba, 0xfe, 0xbf, 0x91, 0x5a, 0x42, 0x5f, 0x70, 0x5b,
-//         0x37, 0xab, 0x8f, 0x4c, 0x75, 0x8d, 0x0c, 0xff, 0x04, 0x95, 0x1c, 0xab, 0x4f, 0x12, 0x73,
-//         0x6a, 0xf5, 0xd8, 0xa1, 0xfb, 0x41, 0x39, 0x52, 0x8d, 0x32, 0xf8, 0x7e, 0x63, 0xc2, 0x0a,
-//         0x2c, 0xa0, 0x72, 0xcb, 0xa2, 0xbb, 0x3c, 0xa4, 0x88, 0x85, 0xe1, 0x5d, 0x07, 0x61, 0xc4,
-//         0x1d, 0xd8, 0xc1, 0x2b, 0x3d, 0x00, 0xe4, 0xa8, 0x8b, 0x66, 0x5b, 0x7c, 0xba, 0xac, 0x4b,
-//         0x9f, 0x6b, 0x57, 0xaf, 0x8f, 0xb2, 0x56, 0x9b, 0x40, 0xb4, 0x93, 0x8d, 0x6c, 0xdb, 0x6f,
-//         0xee, 0xa5, 0xff, 0xa1, 0xde, 0x15, 0xf5, 0x2c, 0x88, 0x92, 0x96, 0xc0, 0x4a, 0x20, 0xe2,
-//         0x1c, 0x7b, 0xdc, 0xee, 0x34, 0xfb, 0x20, 0x59, 0x9b, 0x7e, 0x39, 0x85, 0xf8, 0xdb, 0x05,
-//         0x70, 0xdd, 0x97, 0x48, 0x7b, 0xb9, 0xd1, 0xf6, 0x6d, 0xad, 0x99, 0x3f, 0x47, 0x79, 0xdf,
-//         0xce, 0xb4, 0xf1, 0xe9, 0x4f, 0x42, 0x75, 0xec, 0xff, 0x6a, 0xf5, 0x62, 0x33, 0xbb, 0x30,
-//         0xd7, 0x27, 0x9e, 0x07, 0x66, 0xc6, 0x8d, 0x08, 0xad, 0xc6, 0x36, 0x0e, 0x3f, 0x7c, 0x5b,
-//         0x0e, 0x76, 0xa3, 0x5b, 0x26, 0x57, 0x9f, 0x42, 0x58, 0x0a, 0x0b, 0x16, 0xda, 0xdd, 0x03,
-//         0xf9, 0xdc, 0x6d, 0xb7, 0x41, 0x8f, 0xa7, 0xcf, 0xbb, 0xc1, 0x00, 0x8c, 0xc3, 0x5e, 0x75,
-//         0x5c, 0x39, 0x47, 0x57, 0x39, 0xe1, 0xa5, 0x9b, 0x56, 0x92, 0x70, 0x58, 0x41, 0x24, 0x13,
-//         0x8d, 0x36, 0xaf, 0x1a, 0x46, 0x3a, 0x88, 0x2a, 0xff, 0xef, 0x07, 0x5c, 0x2d, 0x81, 0xf8,
-//         0x3b, 0xbe, 0xa1, 0xcb, 0xdd, 0xcd, 0x02, 0x13, 0x0b, 0xa5, 0x74, 0xd9, 0x08, 0x35, 0xcf,
-//         0x05, 0xe1, 0x47, 0xb0, 0xd2, 0x7a, 0x41, 0x57, 0x5c, 0x0c, 0x66, 0x1b, 0x9a, 0xbe, 0x17,
-//         0xbb, 0x12, 0x10, 0x92, 0xc5, 0xc6, 0x2e, 0x46, 0xf7, 0x16, 0x4b, 0xe2, 0xf8, 0x5f, 0x61,
-//         0x8f, 0x0f, 0x88, 0x6e, 0xb6, 0xf5, 0xf0, 0xee, 0x2b, 0x23, 0xed, 0x36, 0xbd, 0x3b, 0x89,
-//         0x84, 0x89, 0x3e, 0xa6, 0x75, 0xeb, 0x27, 0xe5, 0x41, 0xc9, 0x8e, 0x18, 0x50, 0xf9, 0xb8,
-//         0xef, 0xb8, 0x8f, 0xdf, 0x48, 0x51, 0xaa, 0x6d, 0xee, 0xc3, 0xe8, 0x31, 0xc9, 0xdd, 0xb7,
-//         0x61, 0x23, 0x3a, 0xe3, 0x08, 0x9c, 0x35, 0x79, 0x20, 0x73, 0x32, 0x82, 0x58, 0xd6, 0x52,
-//         0xe3, 0x1d, 0xfb, 0xef, 0x92, 0x1f, 0xae, 0xb8, 0x8e, 0x44, 0x84, 0x8b, 0xcb, 0x26, 0x4e,
-//         0xdf, 0x41, 0x72, 0x33, 0xb7, 0xd9, 0x1f, 0xb5, 0xdf, 0x9e, 0x56, 0x4c, 0x3f, 0x83, 0xdf,
-//         0x59, 0x64, 0x2e, 0x16, 0x27, 0x97, 0x32, 0x38, 0xd7, 0x79, 0xf3, 0x3a, 0x0f, 0x7c, 0xfa,
-//         0x1a, 0x82, 0xfb, 0xbe, 0x29, 0xed, 0x28, 0xa4, 0xc0, 0x6f, 0x9f, 0x83, 0xa7, 0x38, 0x64,
-//         0x88, 0x8c, 0x8b, 0x58, 0xf5, 0xa7, 0x84, 0xce, 0xf4, 0x8b, 0xe1, 0x0e, 0xa4, 0x82, 0x34,
-//         0xbe, 0xe4, 0x32, 0xfc, 0x92, 0x08, 0xdd, 0xb4, 0x60, 0xeb, 0x1b, 0xee, 0x21, 0xc0, 0x99,
-//         0x23, 0xb2, 0x53, 0xcb, 0x71, 0x6d, 0x0a, 0xf8, 0x02, 0x60, 0x26, 0x0a, 0x3e, 0x32, 0xdd,
-//         0xb9, 0x00, 0x83, 0xaf, 0xe5, 0xb9, 0x23, 0x3f, 0x00, 0x18, 0xb2, 0xf3, 0xe8, 0x40, 0x48,
-//         0xce, 0x4f, 0x5f, 0x87, 0xe0, 0x34, 0xc0, 0x8e, 0x28, 0x00, 0xbe, 0xcb, 0x33, 0xbe, 0xd5,
-//         0xab, 0xb9, 0x4b, 0xdf, 0x06, 0x55, 0x65, 0x68, 0x06, 0x08, 0x76, 0x2e, 0x93, 0x9c, 0x64,
-//         0xbf, 0x7a, 0x34, 0x97, 0x54, 0xc5, 0x5e, 0xa6, 0xed, 0xb3, 0x57, 0x1a, 0x5e, 0xab, 0x70,
-//         0xfc, 0x20, 0xb5, 0xc5, 0x6b, 0xd6, 0x25, 0x5f, 0x01, 0x65, 0x36, 0x52, 0x93, 0xc4, 0xb4,
-//         0x3b, 0xf5, 0xe3, 0xa7, 0x4e, 0x78, 0x40, 0x50, 0xe1, 0x71, 0x68, 0x01, 0xd0, 0x7a, 0xb8,
-//         0x5e, 0xab, 0x5d, 0xbf, 0x07, 0x31, 0xae, 0x58, 0x73, 0xa5, 0xe4, 0xfb, 0xed, 0xc4, 0x60,
-//         0x0e, 0x8d, 0x52, 0x8f, 0xbb, 0x31, 0xa9, 0x46, 0xf0, 0x1f, 0x9f, 0xe2, 0x60, 0x39, 0xfd,
-//         0xa5, 0x5f, 0x43, 0x77, 0xb2, 0x28, 0x72, 0x26, 0x2f, 0x99, 0x72, 0x17, 0x78, 0xd8, 0xdf,
-//         0xf1, 0xab, 0xa8, 0x3d, 0xc7, 0x4f, 0x86, 0x04, 0xe6, 0x69, 0x30, 0x0f, 0x33, 0xd0, 0x57,
-//         0x00, 0x14, 0xac, 0x1d, 0xcf, 0xa4, 0x41, 0xe2, 0x26, 0x97, 0x31, 0xe1, 0x07, 0x0c, 0x7b,
-//         0x60, 0x31, 0xc0, 0xa9, 0x10, 0x58, 0x26, 0x09, 0xf4, 0x55, 0x95, 0x05, 0x1d, 0x4d, 0x7e,
-//         0x90, 0x50, 0x15, 0xaf, 0x8e, 0x5e, 0xac, 0x8b, 0x24, 0x4b, 0x90, 0x42, 0x8b, 0x58, 0x83,
-//         0xe5, 0xbc, 0xfc, 0xf1, 0xfe, 0x64, 0x7c, 0xf2, 0x56, 0x12, 0x66, 0x38, 0x07, 0xb0, 0xfd,
-//         0xa4, 0xe7, 0x4b, 0x7d, 0xe2, 0xab, 0xe7, 0x36, 0x56, 0x26, 0x27, 0x52, 0xb8, 0x2a, 0xa6,
-//         0x59, 0xb1, 0xf7, 0xc2, 0x65, 0xce, 0x29, 0x5d, 0x42, 0xb1, 0x82, 0xc5, 0xee, 0x0b, 0xba,
-//         0x52, 0x3e, 0x31, 0xa5, 0x5d, 0x1a, 0xd5, 0x14, 0xb8, 0x89, 0x74, 0xe3, 0x49, 0xea, 0xfc,
-//         0x6e, 0xd6, 0x4c, 0xf0, 0xbd, 0x1e, 0xa5, 0xfd, 0x6a, 0xe3, 0xb0, 0xd1, 0xc9, 0x30, 0xa1,
-//         0x93, 0xf8, 0x80, 0xc5, 0xd6, 0xfc, 0xc9, 0xea, 0x66, 0x09, 0xbf, 0x3f, 0x19, 0xc1, 0xa3,
-//         0x22, 0x92, 0xd4, 0xeb, 0x12, 0xe9, 0xfc, 0xa5, 0xaa, 0x1b, 0xfd, 0xda, 0x60, 0xe8, 0xf6,
-//         0x01, 0xb5, 0x6d, 0x9a, 0x77, 0xf1, 0x34, 0x72, 0xe2, 0x1b, 0x22, 0xb3, 0x70, 0xb8, 0xea,
-//         0xf8, 0x2e, 0xec, 0xac, 0x21, 0x9a, 0x42, 0x22, 0x3a, 0x18, 0x24, 0xd9, 0x52, 0xef, 0x01,
-//         0xbe, 0xbb, 0x19, 0xbe, 0x2c, 0xe2, 0x33, 0x1e, 0x2b, 0x6b, 0x13, 0x8c, 0xc3, 0x27, 0x58,
-//         0x82, 0xd4, 0xb7, 0x40, 0xb4, 0xa3, 0x12, 0x86, 0x7e, 0x9b, 0x30, 0xdf, 0xdb, 0x33, 0x86,
-//         0xdc, 0xb9, 0xf8, 0x87, 0x89, 0x8b, 0x95, 0x20, 0xad, 0x01, 0xc5, 0xf9, 0xfa, 0xd0, 0x94,
-//         0x07, 0x26, 0x12, 0xfd, 0xd7, 0x71, 0x1b, 0xfc, 0xfd, 0x76, 0x1a, 0x6d, 0x9f, 0xec, 0x75,
-//         0x61, 0xc0, 0xbf, 0x32, 0x96, 0x62, 0x43, 0xa1, 0x8a, 0x72, 0x01, 0xb7, 0x96, 0xfe, 0xce,
-//         0x18, 0x0e, 0xee, 0xb9, 0x33, 0x12, 0xd8, 0x4c, 0xaf, 0xf9, 0x06, 0xad, 0xfe, 0x86, 0x2c,
-//         0x3c, 0xdc, 0x45, 0x75, 0x30, 0x77, 0x24, 0x3d, 0xb8, 0xa1, 0x46, 0x0f, 0xe1, 0xe8, 0xdc,
-//         0x3e, 0x5e, 0xad, 0xe0, 0xa0, 0x55, 0x7a, 0x59, 0xe7, 0xcb, 0x8f, 0x26, 0x29, 0x89, 0x1b,
-//         0x43, 0x6e, 0xb2, 0x46, 0x06, 0x04, 0xae, 0xaa, 0x5d, 0xb9, 0xd3, 0xa4, 0x9d, 0xb4, 0xab,
-//         0x8e, 0xf7, 0xb0, 0x0d, 0x86, 0xd0, 0x71, 0x50, 0xc6, 0x18, 0x61, 0xad, 0xdd, 0x07, 0xbb,
-//         0x89, 0xa1, 0x0a, 0x6b, 0x86, 0x53, 0x34, 0x8c, 0xfa, 0xd6, 0xd2, 0xd7, 0xe6, 0x7f, 0xd7,
-//         0x53, 0xd0, 0x2c, 0x2c, 0xbe, 0x13, 0x2e, 0xf2, 0x4e, 0xbc, 0x54, 0xd7, 0x5b, 0xf1, 0x0d,
-//         0xdb, 0x2a, 0x2b, 0x7d, 0xc4, 0x49, 0x79, 0x6c, 0x93, 0xc0, 0x58, 0xbb, 0xe4, 0xf8, 0xf8,
-//         0xd1, 0x79, 0x1b, 0xa7, 0x7a, 0xe1, 0xcf, 0x08, 0xa0, 0xd3, 0x7b, 0x7a, 0x6b, 0xc9, 0x5c,
-//         0x41, 0x34, 0x94, 0x10, 0xd6, 0x23, 0x07, 0xd5, 0xaf, 0x5b, 0x79, 0x57, 0xdf, 0xea, 0x83,
-//         0xf1, 0x40, 0xbc, 0x65, 0x58, 0x64, 0xd7, 0x59, 0x1e, 0x2a, 0x23, 0xb3, 0xc9, 0x11, 0x71,
-//         0x65, 0x99, 0x2f, 0xc7, 0x03, 0xc3, 0x32, 0xab, 0x4a, 0x64, 0x07, 0x6e, 0x0a, 0x45, 0x70,
-//         0x56, 0xb1, 0xb7, 0x13, 0xd9, 0xc1, 0x94, 0xdb, 0x44, 0xbe, 0x81, 0xa1, 0x15, 0x05, 0x45,
-//         0xc0, 0x82, 0x2c, 0x5d, 0xbc, 0xd3, 0x44, 0x82, 0xf0, 0x8e, 0xd5, 0x57, 0xce, 0x92, 0x67,
-//         0x76, 0x8c, 0xfc, 0x5c, 0xfd, 0x78, 0x33, 0x45, 0x99, 0x8c, 0x35, 0x4e, 0x63, 0xd5, 0xf5,
-//         0x26, 0xf2, 0x0f, 0x8e, 0x8c, 0xac, 0x75, 0x1e, 0x0a, 0xdb, 0x6f, 0x02, 0x40, 0x2d, 0xd6,
-//         0x49, 0x9d, 0xc0, 0x96, 0x19, 0x9f, 0x13, 0xdc, 0xeb, 0xdc, 0x3c, 0x7d, 0x5e, 0x74, 0x58,
-//         0xa9, 0x0f, 0x8c, 0x33, 0xeb, 0x3b, 0xd1, 0x8a, 0x9c, 0xf0, 0xfd, 0xa3, 0x5d, 0x79, 0xfc,
-//         0x83, 0xaf, 0xa3, 0x5a, 0x71, 0x29, 0xd3, 0xd1, 0x1c, 0x4d, 0xf1, 0x53, 0x15, 0x46, 0xd3,
-//         0x87, 0x67, 0x3a, 0x78, 0xe4, 0xb0, 0xad, 0x73, 0x4c, 0x60, 0xab, 0x96, 0x69, 0x74, 0x6c,
-//         0x69, 0x76, 0x6d, 0x7b, 0x2a, 0x82, 0xc4, 0xe2, 0xb1, 0x5b, 0x77, 0xba, 0x77, 0xd1, 0xc1,
-//         0xaa, 0x4d, 0x81, 0xf3, 0x43, 0xdc, 0x98, 0xb0, 0x58, 0x7d, 0x7d, 0xb2, 0x22, 0x56, 0x30,
-//         0x12, 0xb8, 0x4d, 0x69, 0x0e, 0x1a, 0xd8, 0x1d, 0x4d, 0xe7, 0xf5, 0xfe, 0x11, 0x18, 0x0e,
-//         0xeb, 0x67, 0x5a, 0x7d, 0xe2, 0xee, 0xe6, 0x83, 0x32, 0x1b, 0x9a, 0x64, 0xc2, 0x21, 0xa2,
-//         0x08, 0xf9, 0x3e, 0x2a, 0x2a, 0x1e, 0x5e, 0xc7, 0xc8, 0x27, 0x8d, 0xad, 0xaf, 0xbe, 0xc0,
-//         0x4d, 0x10, 0xa6, 0xd0, 0xc8, 0x1f, 0x69, 0x94, 0xe1, 0x35, 0x46, 0x4d, 0x10, 0x4e, 0x66,
-//         0xb4, 0x7e, 0xdc, 0x0b, 0x6f, 0x2a, 0x9f, 0x2e, 0x7f, 0xdd, 0x38, 0xc3, 0xa9, 0x70, 0x40,
-//         0x55, 0xb3, 0x0c, 0x0b, 0x6a, 0x61, 0x83, 0x27, 0x58, 0xa6, 0x4c, 0x95, 0xa4, 0x83, 0xcb,
-//         0x5c, 0x0f, 0xb4, 0xe8, 0x4e, 0x7c, 0x4e, 0x6a, 0xf0, 0xb0, 0xc5, 0x65, 0xa2, 0xd3, 0x22,
-//         0x5f, 0x57, 0xca, 0x9e, 0xab, 0xed, 0xc8, 0x4c, 0x87, 0xaa, 0x64, 0x80, 0x95, 0xa4, 0x50,
-//         0xf9, 0x11, 0x44, 0xa1, 0x5e, 0xaf, 0x5e, 0xa4, 0xbf, 0x52, 0x80, 0x7d, 0xbc, 0x7b, 0x73,
-//         0x5e, 0xe6, 0x46, 0x37, 0xf0, 0x9a, 0x2e, 0x32, 0x8f, 0x5d, 0x49, 0xa6, 0x1e, 0x96, 0x5f,
-//         0x61, 0xb9, 0xbe, 0x8c, 0x98, 0xa2, 0x5e, 0x0d, 0xa3, 0x1b, 0x9d, 0x11, 0xed, 0xd8, 0xae,
-//         0x5b, 0x1f, 0xa4, 0x9e, 0x53, 0x2d, 0x94, 0x21, 0xb3, 0x22, 0x63, 0x51, 0x39, 0x72, 0xa5,
-//         0xa3, 0xf8, 0x3a, 0xda, 0xd9, 0xbd, 0xd1, 0x9d, 0x00, 0xe5, 0x04, 0xb5, 0x0a, 0xa2, 0x69,
-//         0x35, 0xff, 0xbf, 0xb9, 0x91, 0x2e, 0x33, 0x0c, 0x21, 0xda, 0x08, 0x0f, 0xd0, 0xae, 0x15,
-//         0xf6, 0x40, 0x5f, 0x86, 0x7f, 0x5e, 0x09, 0x3e, 0x3b, 0x2e, 0x06, 0x00, 0x0f, 0x31, 0x36,
-//         0x88, 0x1c, 0x95, 0x0d, 0x53, 0x9a, 0x59, 0xd0, 0xe0, 0x37, 0x0b, 0xef, 0x9d, 0x98, 0x9e,
-//         0x52, 0x9b, 0x17, 0x26, 0xc6, 0xf2, 0xdf, 0xf3,
-//     ];
-// }
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand::Rng;
+
+    use crate::control_bits::{int32_sort, ControlBits};
+    use crate::field_element::FieldElement;
+    use crate::field_ordering::FieldOrdering;
+    use crate::parameter_set::{McEliece348864, McEliece460896, ParameterSet};
+
+    fn apply_benes<P: ParameterSet>() {
+        let mut expected = vec![0u64; P::Q / 64];
+        rand::thread_rng().fill(&mut expected[..]);
+
+        let mut bits = expected.clone();
+
+        let mut condition_bits = vec![0u8; ControlBits::<P>::BYTES];
+        rand::thread_rng().fill(&mut condition_bits[..]);
+
+        let control_bits = ControlBits::<P>::from_bytes(&condition_bits);
+
+        control_bits.apply_benes(&mut bits, false);
+
+        assert_ne!(bits, expected);
+
+        let reversed_condition_bits: Vec<u8> = condition_bits
+            .rchunks_exact(ControlBits::<P>::LAYER_BYTES)
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut reversed_bits = bits.clone();
+        ControlBits::<P>::from_bytes(&reversed_condition_bits)
+            .apply_benes(&mut reversed_bits, false);
+        assert_eq!(reversed_bits, expected);
+
+        control_bits.apply_benes(&mut bits, true);
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn apply_benes_mceliece348864() {
+        apply_benes::<McEliece348864>();
+    }
+
+    #[test]
+    fn apply_benes_mceliece460896() {
+        apply_benes::<McEliece460896>();
+    }
+
+    fn from_permutation<P: ParameterSet>() {
+        let alpha = loop {
+            let mut seed = vec![0u8; FieldOrdering::<P>::BYTES];
+            rand::thread_rng().fill(&mut seed[..]);
+
+            if let Some(alpha) = FieldOrdering::<P>::new(&seed) {
+                break alpha;
+            }
+        };
+
+        let control_bits = ControlBits::<P>::from(&alpha);
+
+        assert_eq!(*control_bits.generate_support(), *alpha.generate_support());
+        assert!(control_bits.is_canonical());
+
+        let mut values = vec![FieldElement::ZERO; P::Q];
+        for value in values.iter_mut() {
+            *value = rand::thread_rng().gen::<u16>().into();
+        }
+
+        let support_order = control_bits.to_support_order(&values);
+        assert_eq!(*support_order, *alpha.to_support_order(&values));
+
+        let fft_order = control_bits.to_fft_order(&support_order);
+        for (i, pi) in control_bits.permutation().iter().enumerate() {
+            let x = u16::from(*pi) as usize;
+            if i < P::N {
+                assert_eq!(support_order[i], values[x]);
+                assert_eq!(fft_order[x], values[x]);
+            } else {
+                assert_eq!(fft_order[x], FieldElement::ZERO);
+            }
+        }
+    }
+
+    #[test]
+    fn from_permutation_mceliece348864() {
+        from_permutation::<McEliece348864>();
+    }
+
+    #[test]
+    fn from_permutation_mceliece460896() {
+        from_permutation::<McEliece460896>();
+    }
+
+    #[test]
+    fn test_int32_sort() {
+        for n in [0, 1, 2, 3, 7, 64, 100, 4096] {
+            let mut x = vec![0i32; n];
+            rand::thread_rng().fill(&mut x[..]);
+
+            let mut expected = x.clone();
+            expected.sort_unstable();
+
+            int32_sort(&mut x);
+
+            assert_eq!(x, expected);
+        }
+    }
+}
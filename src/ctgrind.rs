@@ -0,0 +1,82 @@
+// Memcheck client requests for checking constant-timeness under valgrind, after Adam Langley's
+// ctgrind: secret data is marked undefined, so that memcheck reports every branch and memory index
+// that depends on it, and values that may be revealed are marked defined again. The requests only
+// do something with the ctgrind feature on x86-64 and under valgrind; otherwise they are no-ops.
+
+#[cfg(all(feature = "ctgrind", target_arch = "x86_64"))]
+mod memcheck {
+    use core::arch::asm;
+
+    // VG_USERREQ__RUNNING_ON_VALGRIND from valgrind.h, and VG_USERREQ_TOOL_BASE('M', 'C') + 1
+    // and + 2 from memcheck.h.
+    pub(super) const RUNNING_ON_VALGRIND: usize = 0x1001;
+    pub(super) const MAKE_MEM_UNDEFINED: usize = 0x4d43_0001;
+    pub(super) const MAKE_MEM_DEFINED: usize = 0x4d43_0002;
+
+    // The special instruction sequence of valgrind.h, which leaves every register unchanged and
+    // returns 0 when not running under valgrind.
+    #[inline]
+    pub(super) fn client_request(request: usize, address: *const u8, len: usize) -> usize {
+        let args = [request, address as usize, len, 0, 0, 0];
+        let result;
+
+        // SAFETY: The rotations of rdi add up to 128 bits, so rdi ends up unchanged even though it
+        // is not declared as an operand. rdx, which valgrind overwrites with the result, is
+        // declared as an output, and the flags the rotations clobber are assumed clobbered since
+        // `preserves_flags` is not given. Valgrind only reads the six words of `args`, which
+        // outlives the block, and nothing is pushed to the stack.
+        unsafe {
+            asm!(
+                "rol rdi, 3",
+                "rol rdi, 13",
+                "rol rdi, 61",
+                "rol rdi, 51",
+                "xchg rbx, rbx",
+                inout("rdx") 0usize => result,
+                in("rax") args.as_ptr(),
+                options(nostack),
+            );
+        }
+
+        result
+    }
+}
+
+// Whether the client requests reach valgrind, for checking that they are recognized at all.
+#[cfg(all(feature = "ctgrind", target_arch = "x86_64"))]
+pub fn running_on_valgrind() -> bool {
+    memcheck::client_request(memcheck::RUNNING_ON_VALGRIND, core::ptr::null(), 0) != 0
+}
+
+#[cfg(all(feature = "ctgrind", not(target_arch = "x86_64")))]
+pub fn running_on_valgrind() -> bool {
+    false
+}
+
+// Marks the bytes of `value` as secret.
+#[inline]
+pub(crate) fn poison<T: ?Sized>(value: &T) {
+    #[cfg(all(feature = "ctgrind", target_arch = "x86_64"))]
+    memcheck::client_request(
+        memcheck::MAKE_MEM_UNDEFINED,
+        value as *const T as *const u8,
+        core::mem::size_of_val(value),
+    );
+
+    #[cfg(not(all(feature = "ctgrind", target_arch = "x86_64")))]
+    let _ = value;
+}
+
+// Marks the bytes of `value` as safe to reveal.
+#[inline]
+pub(crate) fn unpoison<T: ?Sized>(value: &T) {
+    #[cfg(all(feature = "ctgrind", target_arch = "x86_64"))]
+    memcheck::client_request(
+        memcheck::MAKE_MEM_DEFINED,
+        value as *const T as *const u8,
+        core::mem::size_of_val(value),
+    );
+
+    #[cfg(not(all(feature = "ctgrind", target_arch = "x86_64")))]
+    let _ = value;
+}
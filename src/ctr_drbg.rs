@@ -0,0 +1,70 @@
+// The AES-256 CTR_DRBG, without derivation function or reseeding, that the reference
+// implementation behind the NIST KATs draws its randomness from: the key generation seed and the
+// bytes of each attempt of FixedWeight come from a DRBG seeded with the seed of the count.
+pub(crate) struct CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl CtrDrbg {
+    pub(crate) fn new(entropy: &[u8; 48]) -> Self {
+        let mut drbg = CtrDrbg {
+            key: [0; 32],
+            v: [0; 16],
+        };
+        drbg.update(Some(entropy));
+        drbg
+    }
+
+    fn next_block(&mut self) -> [u8; 16] {
+        use aes::cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit};
+
+        for byte in self.v.iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+
+        let mut block = GenericArray::from(self.v);
+        aes::Aes256::new(&self.key.into()).encrypt_block(&mut block);
+        block.into()
+    }
+
+    fn update(&mut self, data: Option<&[u8; 48]>) {
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_exact_mut(16) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+
+        if let Some(data) = data {
+            for (temp, data) in temp.iter_mut().zip(data.iter()) {
+                *temp ^= data;
+            }
+        }
+
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+
+    pub(crate) fn fill(&mut self, output: &mut [u8]) {
+        for chunk in output.chunks_mut(16) {
+            chunk.copy_from_slice(&self.next_block()[..chunk.len()]);
+        }
+        self.update(None);
+    }
+}
+
+// The seeds of the first `n` counts of the KAT files, which come from a DRBG seeded with the bytes
+// 0 to 47.
+pub(crate) fn kat_seeds(n: usize) -> Vec<[u8; 48]> {
+    let mut drbg = CtrDrbg::new(&core::array::from_fn(|i| i as u8));
+
+    (0..n)
+        .map(|_| {
+            let mut seed = [0u8; 48];
+            drbg.fill(&mut seed);
+            seed
+        })
+        .collect()
+}
@@ -0,0 +1,277 @@
+// Statistical timing tests in the style of dudect (Reparaz, Balasch and Verbauwhede, "Dude, is my
+// code constant time?"). Each test times an operation on inputs drawn at random from two classes,
+// one fixed input and fresh random inputs, and compares the two timing distributions with Welch's
+// t-test, both as measured and with slow outliers cropped at several percentiles. Each returns the
+// largest |t|; one at or above THRESHOLD is evidence of a timing leak.
+//
+// Where an operation may reject its input, that decision is public, so both classes are drawn with
+// the same outcome.
+//
+// The tests take minutes and want a quiet machine. benches/dudect.rs runs them:
+//
+//     cargo bench --features dudect --bench dudect [filter]
+
+use std::hint::black_box;
+use std::iter;
+use std::time::Instant;
+
+use rand::{Rng, RngCore};
+
+use crate::ciphertext::Ciphertext;
+use crate::classic_mceliece::ClassicMcEliece;
+use crate::field_element::FieldElement;
+use crate::field_ordering::FieldOrdering;
+use crate::monic_polynomial::MonicPolynomial;
+use crate::parameter_set::*;
+use crate::public_key::{self, PublicKey};
+use crate::secret_key;
+
+pub const THRESHOLD: f64 = 4.5;
+
+const CROPS: usize = 10;
+
+// Operations this short are timed on a batch of inputs at a time.
+const BATCH: usize = 64;
+
+#[derive(Copy, Clone)]
+enum Class {
+    Fixed = 0,
+    Random = 1,
+}
+
+#[derive(Default)]
+struct WelchTest {
+    count: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl WelchTest {
+    fn push(&mut self, class: Class, x: f64) {
+        let c = class as usize;
+        self.count[c] += 1.0;
+        let delta = x - self.mean[c];
+        self.mean[c] += delta / self.count[c];
+        self.m2[c] += delta * (x - self.mean[c]);
+    }
+
+    fn t(&self) -> f64 {
+        let variance_of_mean = |c: usize| self.m2[c] / (self.count[c] - 1.0) / self.count[c];
+        (self.mean[0] - self.mean[1]) / (variance_of_mean(0) + variance_of_mean(1)).sqrt()
+    }
+}
+
+// Times `operation` on `samples` inputs. All inputs are prepared before timing starts, so that
+// preparing them cannot warm up one class only.
+fn max_t<I, O>(
+    samples: usize,
+    mut input: impl FnMut(Class) -> I,
+    mut operation: impl FnMut(I) -> O,
+) -> f64 {
+    let inputs: Vec<_> = (0..samples)
+        .map(|_| {
+            let class = if rand::thread_rng().gen() {
+                Class::Fixed
+            } else {
+                Class::Random
+            };
+            (class, input(class))
+        })
+        .collect();
+
+    let mut measurements = Vec::with_capacity(samples);
+
+    for (class, input) in inputs {
+        let start = Instant::now();
+        let output = black_box(operation(black_box(input)));
+        let nanos = start.elapsed().as_nanos() as f64;
+
+        drop(output);
+        measurements.push((class, nanos));
+    }
+
+    let mut sorted: Vec<f64> = measurements.iter().map(|(_, nanos)| *nanos).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let cutoffs = iter::once(f64::INFINITY).chain((0..CROPS).map(|i| {
+        let percentile = 1.0 - 0.5f64.powf(10.0 * (i + 1) as f64 / CROPS as f64);
+        sorted[(percentile * samples as f64) as usize]
+    }));
+
+    // Cropped sets that are too small give NaN, which f64::max ignores.
+    cutoffs
+        .map(|cutoff| {
+            let mut test = WelchTest::default();
+            for (class, nanos) in measurements.iter().filter(|(_, nanos)| *nanos <= cutoff) {
+                test.push(*class, *nanos);
+            }
+            test.t().abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+pub fn irreducible<P: ParameterSet>(samples: usize) -> f64 {
+    let g_seed = || loop {
+        let g_seed = random_bytes(MonicPolynomial::<P>::BYTES);
+        if MonicPolynomial::<P>::irreducible(&g_seed).is_some() {
+            break g_seed;
+        }
+    };
+
+    let fixed = g_seed();
+
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => fixed.clone(),
+            Class::Random => g_seed(),
+        },
+        |g_seed| MonicPolynomial::<P>::irreducible(&g_seed),
+    )
+}
+
+pub fn public_key_generate<P: ParameterSet>(samples: usize, accepted: bool) -> f64 {
+    let from_seeds = |(alpha_seed, g_seed): &(Vec<u8>, Vec<u8>)| {
+        Some((
+            MonicPolynomial::<P>::irreducible(g_seed)?,
+            FieldOrdering::<P>::new(alpha_seed)?,
+        ))
+    };
+
+    let seeds = || loop {
+        let seeds = (
+            random_bytes(FieldOrdering::<P>::BYTES),
+            random_bytes(MonicPolynomial::<P>::BYTES),
+        );
+        if let Some((g, mut alpha)) = from_seeds(&seeds) {
+            if PublicKey::generate(&g, &mut alpha).is_some() == accepted {
+                break seeds;
+            }
+        }
+    };
+
+    let fixed = seeds();
+
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => from_seeds(&fixed).unwrap(),
+            Class::Random => from_seeds(&seeds()).unwrap(),
+        },
+        |(g, mut alpha)| PublicKey::generate(&g, &mut alpha),
+    )
+}
+
+// Valid ciphertexts against random ones, which almost never decode.
+pub fn decode<P: ParameterSet>(samples: usize) -> f64 {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+    let (ciphertext, _) = public_key.encapsulate();
+    let fixed = ciphertext.as_ref()[..P::C0_BYTES].to_vec();
+
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => fixed.clone(),
+            Class::Random => random_bytes(P::C0_BYTES),
+        },
+        |c0| {
+            let mut error = [0u8; MAX_N_BYTES];
+            let success = secret_key.decode(&c0, &mut error[..P::N_BYTES]);
+            (error, success.unwrap_u8())
+        },
+    )
+}
+
+// The attempts are short enough for the layout of the inputs in memory to show, so they are
+// arrays rather than vectors that the allocator places differently for the two classes.
+pub fn fixed_weight<P: ParameterSet>(samples: usize, accepted: bool) -> f64 {
+    let len = SIGMA_ONE_BYTES * P::TAU;
+
+    let bytes = || loop {
+        let mut bytes = [0u8; SIGMA_ONE_BYTES * 2 * MAX_T];
+        rand::thread_rng().fill_bytes(&mut bytes[..len]);
+        if bool::from(public_key::fixed_weight::<P>(
+            &bytes[..len],
+            &mut [0; MAX_T][..P::T],
+        )) == accepted
+        {
+            break bytes;
+        }
+    };
+
+    let fixed = bytes();
+
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => fixed,
+            Class::Random => bytes(),
+        },
+        |bytes| {
+            let mut ind = [0u16; MAX_T];
+            let success = public_key::fixed_weight::<P>(&bytes[..len], &mut ind[..P::T]);
+            (ind, success.unwrap_u8())
+        },
+    )
+}
+
+// Valid ciphertexts against random ones, which are implicitly rejected.
+pub fn decapsulate<P: ParameterSet>(samples: usize) -> f64 {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+    let (fixed, _) = public_key.encapsulate();
+
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => fixed,
+            Class::Random => {
+                let mut bytes = P::CiphertextBytes::zeroed();
+                rand::thread_rng().fill_bytes(bytes.as_mut());
+                Ciphertext::from_bytes(&bytes)
+            }
+        },
+        |ciphertext| secret_key.decapsulate(&ciphertext),
+    )
+}
+
+// Against 1, where a shortcut would be most likely.
+pub fn inverse<P: ParameterSet>(samples: usize) -> f64 {
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => [FieldElement::<P>::ONE; BATCH],
+            Class::Random => {
+                let mut elements = [FieldElement::ONE; BATCH];
+                for element in elements.iter_mut() {
+                    *element = rand::thread_rng().gen_range(1..(P::Q as u16)).into();
+                }
+                elements
+            }
+        },
+        |elements| elements.map(|element| element.inverse()),
+    )
+}
+
+// Against the syndrome of the zero error vector, for which every discrepancy is zero.
+pub fn berlenkamp_massey<P: ParameterSet>(samples: usize) -> f64 {
+    max_t(
+        samples,
+        |class| match class {
+            Class::Fixed => [FieldElement::<P>::ZERO; 2 * MAX_T],
+            Class::Random => {
+                let mut syndrome = [FieldElement::ZERO; 2 * MAX_T];
+                for element in syndrome[..(2 * P::T)].iter_mut() {
+                    *element = rand::thread_rng().gen::<u16>().into();
+                }
+                syndrome
+            }
+        },
+        |syndrome| secret_key::berlenkamp_massey::<P>(&syndrome[..(2 * P::T)]),
+    )
+}
@@ -0,0 +1,30 @@
+use core::fmt;
+
+/// Why parsing a key or ciphertext, or encapsulating an error vector, failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidLength,
+    InvalidPivots,
+    InvalidGoppaPolynomial,
+    /// Only returned by `SecretKey::from_bytes_checked`.
+    InvalidControlBits,
+    NonzeroPadding,
+    /// Only returned by `PublicKey::encapsulate_with_error_vector`.
+    InvalidWeight,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidLength => "invalid length",
+            Error::InvalidPivots => "invalid pivot field",
+            Error::InvalidGoppaPolynomial => "Goppa polynomial coefficient out of range",
+            Error::InvalidControlBits => "control bits are not canonical",
+            Error::NonzeroPadding => "nonzero padding bits",
+            Error::InvalidWeight => "error vector does not have weight T",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,316 @@
+use zeroize::Zeroizing;
+
+use crate::backend;
+use crate::field_element::FieldElement;
+use crate::parameter_set::{ParameterSet, MAX_M, MAX_T};
+
+const MAX_LEN: usize = 2 * MAX_T;
+
+// The butterflies are done this many at a time.
+const CHUNK_LEN: usize = 64;
+
+// Evaluates the polynomial with coefficients `f` at every field element, following Gao and
+// Mateer's additive FFT. The output is in bit-reversed order: output[x] = f(reverse_bits(x)).
+pub(crate) fn fft<P: ParameterSet>(f: &[FieldElement<P>], output: &mut [FieldElement<P>]) {
+    debug_assert!(f.len() <= MAX_LEN);
+    debug_assert_eq!(output.len(), P::Q);
+
+    let mut coefficients = Zeroizing::new([FieldElement::ZERO; MAX_LEN]);
+    coefficients[..f.len()].copy_from_slice(f);
+
+    fft_recursion(
+        &coefficients[..f.len().next_power_of_two()],
+        &basis::<P>()[..P::M],
+        output,
+    );
+}
+
+// The transpose of `fft`: output[j] is the sum of values[x] * reverse_bits(x)^j over all x.
+// Overwrites `values`.
+pub(crate) fn fft_tr<P: ParameterSet>(
+    values: &mut [FieldElement<P>],
+    output: &mut [FieldElement<P>],
+) {
+    debug_assert_eq!(values.len(), P::Q);
+    debug_assert!(output.len() <= MAX_LEN);
+
+    let mut coefficients = Zeroizing::new([FieldElement::ZERO; MAX_LEN]);
+    let coefficients = &mut coefficients[..output.len().next_power_of_two()];
+
+    fft_tr_recursion(values, &basis::<P>()[..P::M], coefficients);
+
+    output.copy_from_slice(&coefficients[..output.len()]);
+}
+
+// Output bit k selects basis element k, z^(M - 1 - k), which gives the bit-reversed order.
+fn basis<P: ParameterSet>() -> [FieldElement<P>; MAX_M] {
+    let mut basis = [FieldElement::ZERO; MAX_M];
+
+    for (k, element) in basis[..P::M].iter_mut().enumerate() {
+        *element = FieldElement::from(1 << (P::M - 1 - k));
+    }
+
+    basis
+}
+
+// Evaluates f at the span of `basis`, at sum(x_k * basis[k]) for output index x.
+fn fft_recursion<P: ParameterSet>(
+    f: &[FieldElement<P>],
+    basis: &[FieldElement<P>],
+    output: &mut [FieldElement<P>],
+) {
+    debug_assert!(f.len().is_power_of_two());
+    debug_assert_eq!(output.len(), 1 << basis.len());
+
+    if f.len() == 1 {
+        output.fill(f[0]);
+        return;
+    }
+
+    let (last, basis) = basis.split_last().unwrap();
+    let (gamma, delta) = next_basis(basis, *last);
+
+    // Writing f(last * x) = g0(x^2 + x) + x * g1(x^2 + x):
+
+    let mut g = Zeroizing::new([FieldElement::ZERO; MAX_LEN]);
+    let g = &mut g[..f.len()];
+
+    let mut power = FieldElement::ONE;
+    for (g, f) in g.iter_mut().zip(f.iter()) {
+        *g = *f * power;
+        power *= *last;
+    }
+
+    radix_conversion(g);
+
+    let mut g0 = Zeroizing::new([FieldElement::ZERO; MAX_LEN / 2]);
+    let mut g1 = Zeroizing::new([FieldElement::ZERO; MAX_LEN / 2]);
+    let g0 = &mut g0[..(f.len() / 2)];
+    let g1 = &mut g1[..(f.len() / 2)];
+
+    for ((g0, g1), g) in g0.iter_mut().zip(g1.iter_mut()).zip(g.chunks_exact(2)) {
+        *g0 = g[0];
+        *g1 = g[1];
+    }
+
+    let (u, v) = output.split_at_mut(output.len() / 2);
+
+    fft_recursion(g0, &delta[..basis.len()], u);
+    fft_recursion(g1, &delta[..basis.len()], v);
+
+    for (k, (u, v)) in u
+        .chunks_mut(CHUNK_LEN)
+        .zip(v.chunks_mut(CHUNK_LEN))
+        .enumerate()
+    {
+        let points = points(&gamma[..basis.len()], k * CHUNK_LEN, u.len());
+
+        backend::add_products(u, &points[..u.len()], v);
+
+        for (u, v) in u.iter().zip(v.iter_mut()) {
+            *v += *u;
+        }
+    }
+}
+
+fn fft_tr_recursion<P: ParameterSet>(
+    values: &mut [FieldElement<P>],
+    basis: &[FieldElement<P>],
+    f: &mut [FieldElement<P>],
+) {
+    debug_assert!(f.len().is_power_of_two());
+    debug_assert_eq!(values.len(), 1 << basis.len());
+
+    if f.len() == 1 {
+        f[0] = values
+            .iter()
+            .fold(FieldElement::ZERO, |sum, value| sum + *value);
+        return;
+    }
+
+    let (last, basis) = basis.split_last().unwrap();
+    let (gamma, delta) = next_basis(basis, *last);
+
+    let (u, v) = values.split_at_mut(values.len() / 2);
+
+    for (k, (u, v)) in u
+        .chunks_mut(CHUNK_LEN)
+        .zip(v.chunks_mut(CHUNK_LEN))
+        .enumerate()
+    {
+        for (u, v) in u.iter_mut().zip(v.iter()) {
+            *u += *v;
+        }
+
+        let points = points(&gamma[..basis.len()], k * CHUNK_LEN, u.len());
+
+        backend::add_products(v, &points[..u.len()], u);
+    }
+
+    let mut g0 = Zeroizing::new([FieldElement::ZERO; MAX_LEN / 2]);
+    let mut g1 = Zeroizing::new([FieldElement::ZERO; MAX_LEN / 2]);
+    let g0 = &mut g0[..(f.len() / 2)];
+    let g1 = &mut g1[..(f.len() / 2)];
+
+    fft_tr_recursion(u, &delta[..basis.len()], g0);
+    fft_tr_recursion(v, &delta[..basis.len()], g1);
+
+    for ((g0, g1), f) in g0.iter().zip(g1.iter()).zip(f.chunks_exact_mut(2)) {
+        f[0] = *g0;
+        f[1] = *g1;
+    }
+
+    radix_conversion_tr(f);
+
+    let mut power = FieldElement::ONE;
+    for f in f.iter_mut() {
+        *f *= power;
+        power *= *last;
+    }
+}
+
+// Returns gamma = basis / last and delta = gamma^2 + gamma, the basis of the recursive calls.
+fn next_basis<P: ParameterSet>(
+    basis: &[FieldElement<P>],
+    last: FieldElement<P>,
+) -> ([FieldElement<P>; MAX_M], [FieldElement<P>; MAX_M]) {
+    let mut gamma = [FieldElement::ZERO; MAX_M];
+    let mut delta = [FieldElement::ZERO; MAX_M];
+
+    for ((gamma, delta), element) in gamma.iter_mut().zip(delta.iter_mut()).zip(basis.iter()) {
+        *gamma = *element / last;
+        *delta = gamma.square() + *gamma;
+    }
+
+    (gamma, delta)
+}
+
+// Returns the points of the span of `basis` with indices offset..(offset + len).
+fn points<P: ParameterSet>(
+    basis: &[FieldElement<P>],
+    offset: usize,
+    len: usize,
+) -> [FieldElement<P>; CHUNK_LEN] {
+    let mut points = [FieldElement::ZERO; CHUNK_LEN];
+
+    for (i, point) in points[..len].iter_mut().enumerate() {
+        *point = basis
+            .iter()
+            .enumerate()
+            .filter(|(k, _)| ((offset + i) >> k) & 1 == 1)
+            .fold(FieldElement::ZERO, |sum, (_, element)| sum + *element);
+    }
+
+    points
+}
+
+// Rewrites f(x) as sum((f[2i] + f[2i + 1] * x) * (x^2 + x)^i), in place.
+fn radix_conversion<P: ParameterSet>(f: &mut [FieldElement<P>]) {
+    let mut tau = f.len() / 4;
+
+    while tau >= 1 {
+        for block in f.chunks_exact_mut(4 * tau) {
+            for i in 0..tau {
+                block[2 * tau + i] += block[3 * tau + i];
+                block[tau + i] += block[2 * tau + i];
+            }
+        }
+        tau /= 2;
+    }
+}
+
+fn radix_conversion_tr<P: ParameterSet>(f: &mut [FieldElement<P>]) {
+    let mut tau = 1;
+
+    while 4 * tau <= f.len() {
+        for block in f.chunks_exact_mut(4 * tau) {
+            for i in 0..tau {
+                block[2 * tau + i] += block[tau + i];
+                block[3 * tau + i] += block[2 * tau + i];
+            }
+        }
+        tau *= 2;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand::Rng;
+
+    use crate::fft::{fft, fft_tr};
+    use crate::field_element::FieldElement;
+    use crate::monic_polynomial::MonicPolynomial;
+    use crate::parameter_set::*;
+
+    fn random_elements<P: ParameterSet>(elements: &mut [FieldElement<P>]) {
+        for element in elements.iter_mut() {
+            *element = rand::thread_rng().gen::<u16>().into();
+        }
+    }
+
+    fn matches_horner<P: ParameterSet>() {
+        let mut g = MonicPolynomial::<P>([FieldElement::ZERO; MAX_T]);
+        random_elements(&mut g.0[..P::T]);
+
+        let mut f = vec![FieldElement::ONE; P::T + 1];
+        f[..P::T].copy_from_slice(&g.0[..P::T]);
+
+        let mut output = vec![FieldElement::ZERO; P::Q];
+        fft(&f, &mut output);
+
+        for (x, output) in output.iter().enumerate() {
+            let a = FieldElement::<P>::from(x as u16).reverse_bits();
+            assert_eq!(*output, g.evaluate_at(a));
+        }
+    }
+
+    fn fft_tr_is_transpose<P: ParameterSet>() {
+        let mut values = vec![FieldElement::ZERO; P::Q];
+        random_elements(&mut values);
+
+        let mut expected = vec![FieldElement::ZERO; 2 * P::T];
+        for (x, value) in values.iter().enumerate() {
+            let a = FieldElement::<P>::from(x as u16).reverse_bits();
+            let mut power = *value;
+            for expected in expected.iter_mut() {
+                *expected += power;
+                power *= a;
+            }
+        }
+
+        let mut output = vec![FieldElement::ZERO; 2 * P::T];
+        fft_tr(&mut values, &mut output);
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn matches_horner_mceliece348864() {
+        matches_horner::<McEliece348864>();
+    }
+
+    #[test]
+    fn matches_horner_mceliece6960119() {
+        matches_horner::<McEliece6960119>();
+    }
+
+    #[test]
+    fn matches_horner_mceliece8192128() {
+        matches_horner::<McEliece8192128>();
+    }
+
+    #[test]
+    fn fft_tr_is_transpose_mceliece348864() {
+        fft_tr_is_transpose::<McEliece348864>();
+    }
+
+    #[test]
+    fn fft_tr_is_transpose_mceliece6960119() {
+        fft_tr_is_transpose::<McEliece6960119>();
+    }
+
+    #[test]
+    fn fft_tr_is_transpose_mceliece8192128() {
+        fft_tr_is_transpose::<McEliece8192128>();
+    }
+}
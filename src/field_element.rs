@@ -1,18 +1,20 @@
+use core::marker::PhantomData;
+
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::DefaultIsZeroes;
 
-use crate::classic_mceliece::ClassicMcEliece;
+use crate::parameter_set::ParameterSet;
 
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct FieldElement(u16);
-
-impl FieldElement {
-    pub(crate) const MASK: u16 = (1 << ClassicMcEliece::M) - 1;
+#[repr(transparent)]
+pub(crate) struct FieldElement<P: ParameterSet>(u16, PhantomData<P>);
 
-    pub(crate) const ZERO: Self = FieldElement(0);
+impl<P: ParameterSet> FieldElement<P> {
+    pub(crate) const MASK: u16 = (1 << P::M) - 1;
 
-    pub(crate) const ONE: Self = FieldElement(1);
+    pub(crate) const ZERO: Self = FieldElement(0, PhantomData);
 
-    pub(crate) const TWO: Self = FieldElement(2);
+    pub(crate) const ONE: Self = FieldElement(1, PhantomData);
 
     #[inline]
     pub(crate) fn is_valid(&self) -> bool {
@@ -28,7 +30,23 @@ impl FieldElement {
 
     #[inline]
     pub(crate) const fn from_le_bytes(bytes: &[u8; 2]) -> Self {
-        Self(u16::from_le_bytes(*bytes) & Self::MASK)
+        Self(u16::from_le_bytes(*bytes) & Self::MASK, PhantomData)
+    }
+
+    #[inline]
+    pub(crate) const fn to_le_bytes(self) -> [u8; 2] {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn reduce(mut x: u32) -> Self {
+        let polynomial = (1 << P::M) | P::FIELD_POLYNOMIAL as u32;
+
+        for i in (P::M..(2 * P::M - 1)).rev() {
+            x ^= ((x >> i) & 0b1).wrapping_neg() & (polynomial << (i - P::M));
+        }
+
+        (x as u16).into()
     }
 
     pub(crate) fn square(&self) -> Self {
@@ -41,31 +59,23 @@ impl FieldElement {
         x = (x | (x << 2)) & 0x3333_3333;
         x = (x | (x << 1)) & 0x5555_5555;
 
-        let hi = x & 0x007f_c000;
-        x ^= hi >> 9;
-        x ^= hi >> 12;
-
-        let lo = x & 0x0000_3000;
-        x ^= lo >> 9;
-        x ^= lo >> 12;
-
-        (x as u16).into()
+        Self::reduce(x)
     }
 
     pub(crate) fn inverse(&self) -> Self {
         debug_assert!(self.0 != 0);
         self.debug_is_valid();
 
-        let x_pow_0x001 = *self;
-        let x_pow_0x003 = x_pow_0x001 * x_pow_0x001.square();
-        let x_pow_0x00f = x_pow_0x003 * x_pow_0x003.square().square();
-        let x_pow_0x0ff = x_pow_0x00f * x_pow_0x00f.square().square().square().square();
-        let x_pow_0x3ff = x_pow_0x003 * x_pow_0x0ff.square().square();
-        let x_pow_0x7ff = x_pow_0x001 * x_pow_0x3ff.square();
+        let mut x = *self;
+
+        for _ in 2..P::M {
+            x = x.square() * *self;
+        }
 
-        x_pow_0x7ff.square() // x_pow_0xffe aka x^4.094
+        x.square() // x^(2^M - 2)
     }
 
+    #[cfg(feature = "std")]
     pub(crate) fn reverse_bits(&self) -> Self {
         let mut a = self.0;
 
@@ -73,39 +83,49 @@ impl FieldElement {
         a = ((a & 0x0f0f) << 4) | ((a & 0xf0f0) >> 4);
         a = ((a & 0x3333) << 2) | ((a & 0xcccc) >> 2);
         a = ((a & 0x5555) << 1) | ((a & 0xaaaa) >> 1);
-        a >>= 4;
+        a >>= 16 - P::M;
 
-        Self(a)
+        Self(a, PhantomData)
     }
 
     #[inline]
     pub(crate) fn get_bit(&self, k: usize) -> u8 {
-        debug_assert!(k < 12);
+        debug_assert!(k < P::M);
         (self.0 >> k) as u8 & 0b1
     }
 
     #[inline]
-    pub(crate) fn vartime_is_zero(&self) -> bool {
+    pub(crate) fn is_zero_mask(&self) -> Self {
         self.debug_is_valid();
-        self.0 == FieldElement::ZERO.0
+        (((self.0 as u32).wrapping_sub(1) >> 19) as u16).into()
     }
+}
 
+impl<P: ParameterSet> Default for FieldElement<P> {
     #[inline]
-    pub(crate) fn is_zero_mask(&self) -> FieldElement {
-        self.debug_is_valid();
-        (((self.0 as u32).wrapping_sub(1) >> 19) as u16).into()
+    fn default() -> Self {
+        Self::ZERO
     }
 }
 
-impl From<u16> for FieldElement {
+impl<P: ParameterSet> DefaultIsZeroes for FieldElement<P> {}
+
+impl<P: ParameterSet> From<u16> for FieldElement<P> {
     #[inline]
     fn from(fe: u16) -> Self {
-        Self(fe & Self::MASK)
+        Self(fe & Self::MASK, PhantomData)
+    }
+}
+
+impl<P: ParameterSet> From<FieldElement<P>> for u16 {
+    #[inline]
+    fn from(fe: FieldElement<P>) -> Self {
+        fe.0
     }
 }
 
 #[cfg(test)]
-impl PartialEq for FieldElement {
+impl<P: ParameterSet> PartialEq for FieldElement<P> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.debug_is_valid();
@@ -115,15 +135,15 @@ impl PartialEq for FieldElement {
 }
 
 #[cfg(test)]
-impl PartialEq<u16> for FieldElement {
+impl<P: ParameterSet> PartialEq<u16> for FieldElement<P> {
     fn eq(&self, other: &u16) -> bool {
         self.debug_is_valid();
-        Self(*other).debug_is_valid();
+        Self(*other, PhantomData).debug_is_valid();
         self.0 == *other
     }
 }
 
-impl ConstantTimeEq for FieldElement {
+impl<P: ParameterSet> ConstantTimeEq for FieldElement<P> {
     #[inline]
     fn ct_eq(&self, other: &Self) -> Choice {
         self.debug_is_valid();
@@ -132,7 +152,7 @@ impl ConstantTimeEq for FieldElement {
     }
 }
 
-impl ConditionallySelectable for FieldElement {
+impl<P: ParameterSet> ConditionallySelectable for FieldElement<P> {
     #[inline]
     fn conditional_assign(&mut self, other: &Self, choice: Choice) {
         self.debug_is_valid();
@@ -144,31 +164,31 @@ impl ConditionallySelectable for FieldElement {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         a.debug_is_valid();
         b.debug_is_valid();
-        FieldElement(u16::conditional_select(&a.0, &b.0, choice))
+        FieldElement(u16::conditional_select(&a.0, &b.0, choice), PhantomData)
     }
 }
 
 #[allow(clippy::suspicious_arithmetic_impl)]
-impl std::ops::Add for FieldElement {
+impl<P: ParameterSet> core::ops::Add for FieldElement<P> {
     type Output = Self;
 
     #[inline]
     fn add(self, other: Self) -> Self {
         self.debug_is_valid();
         other.debug_is_valid();
-        Self(self.0 ^ other.0)
+        Self(self.0 ^ other.0, PhantomData)
     }
 }
 
 #[allow(clippy::suspicious_op_assign_impl)]
-impl std::ops::AddAssign for FieldElement {
+impl<P: ParameterSet> core::ops::AddAssign for FieldElement<P> {
     #[inline]
     fn add_assign(&mut self, other: Self) {
         *self = *self + other;
     }
 }
 
-impl std::ops::Mul for FieldElement {
+impl<P: ParameterSet> core::ops::Mul for FieldElement<P> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
@@ -178,24 +198,16 @@ impl std::ops::Mul for FieldElement {
         let lhs = self.0 as u32;
         let rhs = other.0 as u32;
 
-        let mut x = (0..ClassicMcEliece::M)
+        let x = (0..P::M)
             .map(|bit| lhs.wrapping_mul(rhs & (1 << bit)))
             .fold(0, |acc, x| acc ^ x);
 
-        let hi = x & 0x007f_c000;
-        x ^= hi >> 9;
-        x ^= hi >> 12;
-
-        let lo = x & 0x0000_3000;
-        x ^= lo >> 9;
-        x ^= lo >> 12;
-
-        (x as u16).into()
+        Self::reduce(x)
     }
 }
 
 #[allow(clippy::suspicious_op_assign_impl)]
-impl std::ops::MulAssign for FieldElement {
+impl<P: ParameterSet> core::ops::MulAssign for FieldElement<P> {
     #[inline]
     fn mul_assign(&mut self, other: Self) {
         *self = *self * other;
@@ -203,7 +215,7 @@ impl std::ops::MulAssign for FieldElement {
 }
 
 #[allow(clippy::suspicious_arithmetic_impl)]
-impl std::ops::Div for FieldElement {
+impl<P: ParameterSet> core::ops::Div for FieldElement<P> {
     type Output = Self;
 
     #[inline]
@@ -211,3 +223,88 @@ impl std::ops::Div for FieldElement {
         self * other.inverse()
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::field_element::FieldElement;
+    use crate::parameter_set::*;
+
+    fn field_axioms<P: ParameterSet>(x: u16, y: u16, z: u16) {
+        let (x, y, z) = (
+            FieldElement::<P>::from(x),
+            FieldElement::<P>::from(y),
+            FieldElement::<P>::from(z),
+        );
+
+        assert_eq!((x + y) + z, x + (y + z));
+        assert_eq!((x * y) * z, x * (y * z));
+        assert_eq!(x + y, y + x);
+        assert_eq!(x * y, y * x);
+        assert_eq!(x * (y + z), x * y + x * z);
+        assert_eq!(x + FieldElement::ZERO, x);
+        assert_eq!(x * FieldElement::ONE, x);
+        assert_eq!(x + x, FieldElement::ZERO);
+    }
+
+    fn inverse<P: ParameterSet>(x: u16) {
+        let x = FieldElement::<P>::from(x);
+        assert_eq!(x * x.inverse(), FieldElement::ONE);
+        assert_eq!(x.inverse().inverse(), x);
+    }
+
+    fn square<P: ParameterSet>(x: u16) {
+        let x = FieldElement::<P>::from(x);
+        assert_eq!(x.square(), x * x);
+    }
+
+    fn reverse_bits<P: ParameterSet>(x: u16) {
+        let x = FieldElement::<P>::from(x);
+        assert!(x.reverse_bits().is_valid());
+        assert_eq!(x.reverse_bits().reverse_bits(), x);
+    }
+
+    // McEliece348864 has GF(2^12); the other parameter sets share the GF(2^13) of McEliece8192128.
+    proptest! {
+        #[test]
+        fn field_axioms_mceliece348864(x: u16, y: u16, z: u16) {
+            field_axioms::<McEliece348864>(x, y, z);
+        }
+
+        #[test]
+        fn field_axioms_mceliece8192128(x: u16, y: u16, z: u16) {
+            field_axioms::<McEliece8192128>(x, y, z);
+        }
+
+        #[test]
+        fn inverse_mceliece348864(x in 1..(McEliece348864::Q as u16)) {
+            inverse::<McEliece348864>(x);
+        }
+
+        #[test]
+        fn inverse_mceliece8192128(x in 1..(McEliece8192128::Q as u16)) {
+            inverse::<McEliece8192128>(x);
+        }
+
+        #[test]
+        fn square_mceliece348864(x: u16) {
+            square::<McEliece348864>(x);
+        }
+
+        #[test]
+        fn square_mceliece8192128(x: u16) {
+            square::<McEliece8192128>(x);
+        }
+
+        #[test]
+        fn reverse_bits_mceliece348864(x: u16) {
+            reverse_bits::<McEliece348864>(x);
+        }
+
+        #[test]
+        fn reverse_bits_mceliece8192128(x: u16) {
+            reverse_bits::<McEliece8192128>(x);
+        }
+    }
+}
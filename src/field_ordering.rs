@@ -1,19 +1,24 @@
-use std::convert::TryInto;
+use core::convert::TryInto;
 
 use djbsort::ConstantTimeSort;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
-use crate::classic_mceliece::ClassicMcEliece;
+use crate::buffer::{Buffer, NBuffer, QBuffer};
 use crate::control_bits::ControlBits;
+use crate::ctgrind;
 use crate::field_element::FieldElement;
+use crate::parameter_set::{ParameterSet, SIGMA_TWO_BYTES};
 
 #[derive(Debug)]
-pub(crate) struct FieldOrdering([FieldElement; ClassicMcEliece::Q]);
+pub(crate) struct FieldOrdering<P: ParameterSet>(QBuffer<P, FieldElement<P>>);
 
-impl FieldOrdering {
-    const BYTES: usize = ClassicMcEliece::SIGMA_TWO_BYTES * ClassicMcEliece::Q;
+impl<P: ParameterSet> FieldOrdering<P> {
+    pub(crate) const BYTES: usize = SIGMA_TWO_BYTES * P::Q;
 
-    pub(crate) fn new(seed: &[u8; Self::BYTES]) -> Option<Self> {
-        let mut pairs = [0u64; ClassicMcEliece::Q];
+    pub(crate) fn new(seed: &[u8]) -> Option<Self> {
+        debug_assert_eq!(seed.len(), Self::BYTES);
+
+        let mut pairs = QBuffer::<P, u64>::new(0, P::Q);
 
         for (i, (pair, bytes)) in pairs.iter_mut().zip(seed.chunks_exact(4)).enumerate() {
             *pair = i as u64;
@@ -22,11 +27,17 @@ impl FieldOrdering {
 
         pairs.ct_sort();
 
-        if pairs.windows(2).any(|w| w[0] >> 31 == w[1] >> 31) {
+        let has_duplicates = pairs.windows(2).fold(Choice::from(0), |has_duplicates, w| {
+            has_duplicates | (w[0] >> 31).ct_eq(&(w[1] >> 31))
+        });
+
+        ctgrind::unpoison(&has_duplicates);
+
+        if bool::from(has_duplicates) {
             return None;
         }
 
-        let mut alpha = Self([FieldElement::ZERO; ClassicMcEliece::Q]);
+        let mut alpha = Self(Buffer::new(FieldElement::ZERO, P::Q));
 
         for (alpha, pair) in alpha.0.iter_mut().zip(pairs.iter()) {
             *alpha = (*pair as u16).into();
@@ -36,76 +47,90 @@ impl FieldOrdering {
     }
 
     #[inline]
-    pub(crate) fn generate_support(&self) -> [FieldElement; ClassicMcEliece::N] {
-        let mut support = [FieldElement::ZERO; ClassicMcEliece::N];
+    pub(crate) fn conditional_swap(&mut self, i: usize, j: usize, choice: Choice) {
+        debug_assert!(i < j);
+        let (lhs, rhs) = self.0.split_at_mut(j);
+        FieldElement::conditional_swap(&mut lhs[i], &mut rhs[0], choice);
+    }
+
+    #[inline]
+    pub(crate) fn generate_support(&self) -> NBuffer<P, FieldElement<P>> {
+        let mut support = Buffer::new(FieldElement::ZERO, P::N);
 
-        for (support, alpha) in support.iter_mut().zip(self.0[..ClassicMcEliece::N].iter()) {
+        for (support, alpha) in support.iter_mut().zip(self.0[..P::N].iter()) {
             *support = alpha.reverse_bits();
         }
 
         support
     }
-}
 
-impl Into<ControlBits> for &FieldOrdering {
-    #[inline]
-    fn into(self) -> ControlBits {
-        let mut control_bits = [0; ControlBits::BYTES];
-
-        unsafe {
-            controlbitsfrompermutation(
-                control_bits.as_mut_ptr(),
-                std::slice::from_raw_parts(self.0.as_ptr() as *const i16, ClassicMcEliece::Q)
-                    .as_ptr(),
-                ClassicMcEliece::M as i64,
-                ClassicMcEliece::Q as i64,
-            );
+    // Takes the output of `fft` to the order of the support like `ControlBits::to_support_order`,
+    // but by sorting twice instead of with the Benes network.
+    pub(crate) fn to_support_order(
+        &self,
+        values: &[FieldElement<P>],
+    ) -> NBuffer<P, FieldElement<P>> {
+        debug_assert_eq!(values.len(), P::Q);
+
+        let mut pairs = QBuffer::<P, u64>::new(0, P::Q);
+
+        for (i, (pair, alpha)) in pairs.iter_mut().zip(self.0.iter()).enumerate() {
+            *pair = ((u16::from(*alpha) as u64) << 16) | i as u64;
         }
 
-        ControlBits::from_bytes(&control_bits)
+        pairs.ct_sort(); // pairs[x] = (x, i) with π(i) = x
+
+        for (pair, value) in pairs.iter_mut().zip(values.iter()) {
+            *pair = ((*pair & 0xffff) << 16) | u16::from(*value) as u64;
+        }
+
+        pairs.ct_sort(); // pairs[i] = (i, values[π(i)])
+
+        let mut output = Buffer::new(FieldElement::ZERO, P::N);
+
+        for (output, pair) in output.iter_mut().zip(pairs.iter()) {
+            *output = (*pair as u16).into();
+        }
+
+        output
     }
 }
 
-extern "C" {
-    fn controlbitsfrompermutation(
-        out: *mut ::std::os::raw::c_uchar,
-        pi: *const i16,
-        w: ::std::os::raw::c_longlong,
-        n: ::std::os::raw::c_longlong,
-    );
+impl<P: ParameterSet> From<&FieldOrdering<P>> for ControlBits<P> {
+    #[inline]
+    fn from(alpha: &FieldOrdering<P>) -> Self {
+        ControlBits::from_permutation(&alpha.0)
+    }
 }
 
 #[cfg(test)]
-impl PartialEq<[u16; ClassicMcEliece::Q]> for FieldOrdering {
-    fn eq(&self, other: &[u16; ClassicMcEliece::Q]) -> bool {
-        self.0.iter().zip(other).all(|(lhs, rhs)| lhs == rhs)
+impl<P: ParameterSet> PartialEq<[u16]> for FieldOrdering<P> {
+    fn eq(&self, other: &[u16]) -> bool {
+        other.len() == P::Q && self.0.iter().zip(other).all(|(lhs, rhs)| lhs == rhs)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::convert::TryInto;
-
     use hex_literal::hex;
 
     use crate::field_ordering::FieldOrdering;
     use crate::generator::Generator;
+    use crate::parameter_set::McEliece348864;
 
     #[test]
     fn test_field_ordering() {
         let mut generator = Generator::new(
             64,
-            &hex!("5b815c890117893d8bb8e886f63a78ce2d5f58342d703348cb95539e14b9a719")
-                .try_into()
-                .unwrap(),
+            &hex!("5b815c890117893d8bb8e886f63a78ce2d5f58342d703348cb95539e14b9a719"),
         );
 
         generator.skip(436);
 
-        let mut seed = [0u8; FieldOrdering::BYTES];
+        let mut seed = [0u8; FieldOrdering::<McEliece348864>::BYTES];
         generator.squeeze(&mut seed);
 
-        let alpha = FieldOrdering::new(&seed).unwrap();
+        let alpha = FieldOrdering::<McEliece348864>::new(&seed).unwrap();
 
         assert_eq!(
             alpha,
@@ -452,7 +477,7 @@ mod tests {
                 0xfd8, 0x16d, 0xa8e, 0x23d, 0x5b8, 0xb13, 0x72d, 0xad0, 0x61d, 0x806, 0x5df, 0x887,
                 0x795, 0xeae, 0x659, 0x772, 0x53c, 0xbf2, 0x4be, 0xc31, 0x46a, 0x082, 0x47d, 0x99c,
                 0xf27, 0xbe7, 0xf23, 0x966,
-            ]
+            ][..]
         );
     }
 }
@@ -0,0 +1,175 @@
+use core::marker::PhantomData;
+
+use zeroize::{DefaultIsZeroes, Zeroizing};
+
+use crate::field_element::FieldElement;
+use crate::parameter_set::{ParameterSet, MAX_M};
+
+// 64 field elements, bitsliced: bit j of word k is bit k of element j.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FieldVector<P: ParameterSet>([u64; MAX_M], PhantomData<P>);
+
+impl<P: ParameterSet> FieldVector<P> {
+    pub(crate) const LANES: usize = 64;
+
+    pub(crate) const ZERO: Self = FieldVector([0; MAX_M], PhantomData);
+
+    // Missing elements are zero.
+    pub(crate) fn from_elements(elements: &[FieldElement<P>]) -> Self {
+        debug_assert!(elements.len() <= Self::LANES);
+
+        let mut vector = Self::ZERO;
+
+        for (j, element) in elements.iter().enumerate() {
+            for (k, word) in vector.0[..P::M].iter_mut().enumerate() {
+                *word |= (element.get_bit(k) as u64) << j;
+            }
+        }
+
+        vector
+    }
+
+    pub(crate) fn write_elements(&self, output: &mut [FieldElement<P>]) {
+        debug_assert!(output.len() <= Self::LANES);
+
+        for (j, output) in output.iter_mut().enumerate() {
+            *output = self.0[..P::M]
+                .iter()
+                .rev()
+                .fold(0, |element, word| (element << 1) | ((word >> j) & 1) as u16)
+                .into();
+        }
+    }
+
+    #[inline]
+    fn reduce(mut x: [u64; 2 * MAX_M - 1]) -> Self {
+        for i in (P::M..(2 * P::M - 1)).rev() {
+            for bit in (0..P::M).filter(|bit| (P::FIELD_POLYNOMIAL >> bit) & 1 == 1) {
+                x[i - P::M + bit] ^= x[i];
+            }
+        }
+
+        let mut vector = Self::ZERO;
+        vector.0[..P::M].copy_from_slice(&x[..P::M]);
+        vector
+    }
+
+    pub(crate) fn square(&self) -> Self {
+        let mut x = [0; 2 * MAX_M - 1];
+
+        for (i, word) in self.0[..P::M].iter().enumerate() {
+            x[2 * i] = *word;
+        }
+
+        Self::reduce(x)
+    }
+
+    // Zero elements are mapped to zero.
+    pub(crate) fn inverse(&self) -> Self {
+        let mut x = *self;
+
+        for _ in 2..P::M {
+            x = x.square() * *self;
+        }
+
+        x.square() // x^(2^M - 2)
+    }
+}
+
+// Inverts every element, 64 at a time; zero is mapped to zero.
+pub(crate) fn invert<P: ParameterSet>(elements: &mut [FieldElement<P>]) {
+    for elements in elements.chunks_mut(FieldVector::<P>::LANES) {
+        let vector = Zeroizing::new(FieldVector::from_elements(elements));
+        vector.inverse().write_elements(elements);
+    }
+}
+
+impl<P: ParameterSet> Default for FieldVector<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<P: ParameterSet> DefaultIsZeroes for FieldVector<P> {}
+
+impl<P: ParameterSet> core::ops::Mul for FieldVector<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut x = [0; 2 * MAX_M - 1];
+
+        for (i, lhs) in self.0[..P::M].iter().enumerate() {
+            for (j, rhs) in other.0[..P::M].iter().enumerate() {
+                x[i + j] ^= lhs & rhs;
+            }
+        }
+
+        Self::reduce(x)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand::Rng;
+
+    use crate::field_element::FieldElement;
+    use crate::field_vector::{invert, FieldVector};
+    use crate::parameter_set::*;
+
+    fn random_elements<P: ParameterSet>() -> [FieldElement<P>; 64] {
+        let mut elements = [FieldElement::ZERO; 64];
+        for element in elements.iter_mut() {
+            *element = rand::thread_rng().gen::<u16>().into();
+        }
+        elements[0] = FieldElement::ZERO;
+        elements[1] = FieldElement::ONE;
+        elements
+    }
+
+    fn matches_scalar_arithmetic<P: ParameterSet>() {
+        let a = random_elements::<P>();
+        let b = random_elements::<P>();
+
+        let (x, y) = (
+            FieldVector::from_elements(&a),
+            FieldVector::from_elements(&b),
+        );
+
+        let mut output = [FieldElement::ZERO; 64];
+
+        x.write_elements(&mut output);
+        assert_eq!(output, a);
+
+        (x * y).write_elements(&mut output);
+        for (output, (a, b)) in output.iter().zip(a.iter().zip(b.iter())) {
+            assert_eq!(*output, *a * *b);
+        }
+
+        x.square().write_elements(&mut output);
+        for (output, a) in output.iter().zip(a.iter()) {
+            assert_eq!(*output, a.square());
+        }
+
+        let mut output = [FieldElement::ZERO; 100];
+        output[..64].copy_from_slice(&a);
+        output[64..].copy_from_slice(&b[..36]);
+        invert(&mut output);
+        assert_eq!(output[0], FieldElement::ZERO);
+        for (output, a) in output.iter().zip(a.iter().chain(b.iter())) {
+            if *a != FieldElement::ZERO {
+                assert_eq!(*output, a.inverse());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_scalar_arithmetic_mceliece348864() {
+        matches_scalar_arithmetic::<McEliece348864>();
+    }
+
+    #[test]
+    fn matches_scalar_arithmetic_mceliece8192128() {
+        matches_scalar_arithmetic::<McEliece8192128>();
+    }
+}
@@ -0,0 +1,113 @@
+// Differential checks of internals against naive references, for the targets in fuzz/. Each
+// panics if the two disagree.
+
+use crate::control_bits::ControlBits;
+use crate::field_element::FieldElement;
+use crate::parameter_set::{ParameterSet, MAX_Q};
+
+// Shift-and-add multiplication, reducing after every shift.
+fn naive_mul<P: ParameterSet>(a: u16, b: u16) -> u16 {
+    let mut a = a;
+    let mut product = 0;
+
+    for i in 0..P::M {
+        if (b >> i) & 0b1 == 1 {
+            product ^= a;
+        }
+        a <<= 1;
+        if (a >> P::M) & 0b1 == 1 {
+            a ^= (1 << P::M) | P::FIELD_POLYNOMIAL;
+        }
+    }
+
+    product
+}
+
+// By exhaustive search.
+fn naive_inverse<P: ParameterSet>(a: u16) -> u16 {
+    (1..(P::Q as u16))
+        .find(|&b| naive_mul::<P>(a, b) == 1)
+        .unwrap()
+}
+
+pub fn field_arithmetic<P: ParameterSet>(a: u16, b: u16) {
+    let (x, y) = (FieldElement::<P>::from(a), FieldElement::<P>::from(b));
+    let (a, b) = (u16::from(x), u16::from(y));
+
+    assert_eq!(u16::from(x * y), naive_mul::<P>(a, b));
+    assert_eq!(u16::from(x.square()), naive_mul::<P>(a, a));
+
+    if a != 0 {
+        assert_eq!(u16::from(x.inverse()), naive_inverse::<P>(a));
+    }
+}
+
+// Draws a permutation by a Fisher–Yates shuffle that takes its randomness from `bytes`, and
+// checks the support given by its control bits against applying it directly.
+pub fn generate_support<P: ParameterSet>(bytes: &[u8]) {
+    let mut permutation = [FieldElement::<P>::ZERO; MAX_Q];
+    let permutation = &mut permutation[..P::Q];
+
+    for (i, element) in permutation.iter_mut().enumerate() {
+        *element = (i as u16).into();
+    }
+
+    if !bytes.is_empty() {
+        let mut bytes = bytes.chunks(2).cycle();
+        for i in (1..P::Q).rev() {
+            let random = bytes
+                .next()
+                .unwrap()
+                .iter()
+                .fold(0, |acc, &b| acc << 8 | b as usize);
+            permutation.swap(i, random % (i + 1));
+        }
+    }
+
+    let control_bits = ControlBits::<P>::from_permutation(permutation);
+    let support = control_bits.generate_support();
+
+    for (support, alpha) in support.iter().zip(permutation[..P::N].iter()) {
+        assert_eq!(u16::from(*support), u16::from(alpha.reverse_bits()));
+    }
+
+    assert!(ControlBits::<P>::from_bytes(control_bits.as_bytes()).is_canonical());
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand::RngCore;
+
+    use super::*;
+    use crate::parameter_set::*;
+
+    #[test]
+    fn field_arithmetic_mceliece348864() {
+        for a in 0..(McEliece348864::Q as u16) {
+            field_arithmetic::<McEliece348864>(a, a.wrapping_mul(0x9e37));
+        }
+    }
+
+    #[test]
+    fn field_arithmetic_mceliece8192128() {
+        for a in (0..(McEliece8192128::Q as u16)).step_by(7) {
+            field_arithmetic::<McEliece8192128>(a, a.wrapping_mul(0x9e37));
+        }
+    }
+
+    #[test]
+    fn generate_support_identity() {
+        generate_support::<McEliece348864>(&[]);
+        generate_support::<McEliece6960119>(&[]);
+    }
+
+    #[test]
+    fn generate_support_random() {
+        let mut bytes = [0u8; 64];
+        rand::thread_rng().fill_bytes(&mut bytes);
+
+        generate_support::<McEliece348864>(&bytes);
+        generate_support::<McEliece6960119>(&bytes);
+        generate_support::<McEliece8192128>(&bytes);
+    }
+}
@@ -1,15 +1,15 @@
-use crate::classic_mceliece::ClassicMcEliece;
+use crate::parameter_set::L_BYTES;
 
 pub(crate) struct Generator {
     reader: sha3::Sha3XofReader,
 }
 
 impl Generator {
-    pub(crate) fn new(domain: u8, seed: &[u8; ClassicMcEliece::L_BYTES]) -> Self {
+    pub(crate) fn new(domain: u8, seed: &[u8; L_BYTES]) -> Self {
         use digest::{ExtendableOutput, Update};
 
         let mut hasher = sha3::Shake256::default();
-        hasher.update(&[domain]);
+        hasher.update([domain]);
         hasher.update(seed);
 
         Generator {
@@ -21,19 +21,19 @@ impl Generator {
         digest::XofReader::read(&mut self.reader, output);
     }
 
-    #[cfg(test)]
+    #[cfg(all(test, feature = "std"))]
     pub(crate) fn squeeze_boxed(&mut self, count: usize) -> Box<[u8]> {
         digest::XofReader::read_boxed(&mut self.reader, count)
     }
 
-    #[cfg(test)]
+    #[cfg(all(test, feature = "std"))]
     pub(crate) fn skip(&mut self, count: u64) {
         use std::io::{copy, sink, Read};
         copy(&mut self.reader.by_ref().take(count), &mut sink()).unwrap();
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use hex_literal::hex;
 
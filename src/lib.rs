@@ -1,82 +1,293 @@
+//! Classic McEliece, with all ten parameter sets of the NIST submission.
+//!
+//! Key generation, encapsulation and decapsulation are constant-time, in safe Rust; building the
+//! crate needs no C toolchain. On x86-64 with `std`, AVX2 is used when the CPU supports it.
+//!
+//! # Features
+//!
+//! - `std` (default): key generation, `rand::thread_rng`, and public keys and working buffers on
+//!   the heap, so that every operation runs on a 256 KB thread stack. Without it the crate is
+//!   `no_std` without `alloc`: parsing keys, `PublicKey::encapsulate_with_rng` and
+//!   `SecretKey::decapsulate` remain, with their buffers on the stack and sized for the parameter
+//!   set. Parsing a secret key and decapsulating then take about 80 KB of stack for
+//!   mceliece348864 and 155 KB for mceliece8192128 in release builds, as `tests/stack.rs` checks.
+//! - `hazmat`: deterministic encapsulation, for reproducing known answers.
+//! - `kem`: the `Encapsulate` and `Decapsulate` traits of the RustCrypto `kem` crate.
+//! - `ctgrind`, `dudect` and `fuzzing`: constant-time checks under valgrind, statistical timing
+//!   tests and internals for the `cargo fuzz` targets in `fuzz/`; for development only.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod backend;
+mod buffer;
+mod ciphertext;
 mod classic_mceliece;
 mod control_bits;
+mod ctgrind;
+#[cfg(all(test, feature = "std"))]
+mod ctr_drbg;
+#[cfg(feature = "dudect")]
+#[doc(hidden)]
+pub mod dudect;
+mod error;
+mod fft;
 mod field_element;
+#[cfg(feature = "std")]
 mod field_ordering;
+mod field_vector;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
 mod generator;
 mod hash;
 mod monic_polynomial;
+mod parameter_set;
 mod public_key;
 mod secret_key;
+mod session_key;
 
+pub use crate::ciphertext::Ciphertext;
 pub use crate::classic_mceliece::ClassicMcEliece;
+#[cfg(feature = "ctgrind")]
+#[doc(hidden)]
+pub use crate::ctgrind::running_on_valgrind;
+pub use crate::error::Error;
+pub use crate::parameter_set::{
+    ByteArray, McEliece348864, McEliece348864f, McEliece460896, McEliece460896f, McEliece6688128,
+    McEliece6688128f, McEliece6960119, McEliece6960119f, McEliece8192128, McEliece8192128f,
+    ParameterSet,
+};
 pub use crate::public_key::PublicKey;
 pub use crate::secret_key::SecretKey;
+pub use crate::session_key::SessionKey;
+
+pub mod mceliece348864 {
+    use crate::parameter_set::McEliece348864;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece348864>;
+    pub type PublicKey = crate::PublicKey<McEliece348864>;
+    pub type SecretKey = crate::SecretKey<McEliece348864>;
+    pub type Ciphertext = crate::Ciphertext<McEliece348864>;
+}
+
+pub mod mceliece460896 {
+    use crate::parameter_set::McEliece460896;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece460896>;
+    pub type PublicKey = crate::PublicKey<McEliece460896>;
+    pub type SecretKey = crate::SecretKey<McEliece460896>;
+    pub type Ciphertext = crate::Ciphertext<McEliece460896>;
+}
+
+pub mod mceliece6688128 {
+    use crate::parameter_set::McEliece6688128;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece6688128>;
+    pub type PublicKey = crate::PublicKey<McEliece6688128>;
+    pub type SecretKey = crate::SecretKey<McEliece6688128>;
+    pub type Ciphertext = crate::Ciphertext<McEliece6688128>;
+}
+
+pub mod mceliece6960119 {
+    use crate::parameter_set::McEliece6960119;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece6960119>;
+    pub type PublicKey = crate::PublicKey<McEliece6960119>;
+    pub type SecretKey = crate::SecretKey<McEliece6960119>;
+    pub type Ciphertext = crate::Ciphertext<McEliece6960119>;
+}
+
+pub mod mceliece8192128 {
+    use crate::parameter_set::McEliece8192128;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece8192128>;
+    pub type PublicKey = crate::PublicKey<McEliece8192128>;
+    pub type SecretKey = crate::SecretKey<McEliece8192128>;
+    pub type Ciphertext = crate::Ciphertext<McEliece8192128>;
+}
+
+pub mod mceliece348864f {
+    use crate::parameter_set::McEliece348864f;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece348864f>;
+    pub type PublicKey = crate::PublicKey<McEliece348864f>;
+    pub type SecretKey = crate::SecretKey<McEliece348864f>;
+    pub type Ciphertext = crate::Ciphertext<McEliece348864f>;
+}
+
+pub mod mceliece460896f {
+    use crate::parameter_set::McEliece460896f;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece460896f>;
+    pub type PublicKey = crate::PublicKey<McEliece460896f>;
+    pub type SecretKey = crate::SecretKey<McEliece460896f>;
+    pub type Ciphertext = crate::Ciphertext<McEliece460896f>;
+}
 
-#[cfg(test)]
+pub mod mceliece6688128f {
+    use crate::parameter_set::McEliece6688128f;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece6688128f>;
+    pub type PublicKey = crate::PublicKey<McEliece6688128f>;
+    pub type SecretKey = crate::SecretKey<McEliece6688128f>;
+    pub type Ciphertext = crate::Ciphertext<McEliece6688128f>;
+}
+
+pub mod mceliece6960119f {
+    use crate::parameter_set::McEliece6960119f;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece6960119f>;
+    pub type PublicKey = crate::PublicKey<McEliece6960119f>;
+    pub type SecretKey = crate::SecretKey<McEliece6960119f>;
+    pub type Ciphertext = crate::Ciphertext<McEliece6960119f>;
+}
+
+pub mod mceliece8192128f {
+    use crate::parameter_set::McEliece8192128f;
+
+    pub type ClassicMcEliece = crate::ClassicMcEliece<McEliece8192128f>;
+    pub type PublicKey = crate::PublicKey<McEliece8192128f>;
+    pub type SecretKey = crate::SecretKey<McEliece8192128f>;
+    pub type Ciphertext = crate::Ciphertext<McEliece8192128f>;
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::convert::TryInto;
-
-    use crate::{ClassicMcEliece, PublicKey, SecretKey};
-
-    #[test]
-    #[ignore = "slow in debug; stack overflow"]
-    fn nist_kats() {
-        #[derive(serde::Deserialize)]
-        struct KAT {
-            count: usize,
-            seed: String,
-            pk: String,
-            sk: String,
-            ct: String,
-            ss: String,
-            e: String,
-        }
+    use std::collections::HashMap;
+
+    use crate::classic_mceliece::ClassicMcEliece;
+    use crate::ctr_drbg::{kat_seeds, CtrDrbg};
+    use crate::parameter_set::*;
+    use crate::public_key::{error_vector, fixed_weight, PublicKey};
+    use crate::secret_key::SecretKey;
 
-        let kats: Vec<KAT> = serde_json::from_slice(include_bytes!("../tests/kats.json")).unwrap();
+    // Reads the records of a NIST `kat_kem.rsp` file, such as "count = 0", "seed = ...".
+    fn load_rsp(name: &str) -> Vec<HashMap<String, String>> {
+        let path = format!("{}/tests/kats/{}.rsp", env!("CARGO_MANIFEST_DIR"), name);
+        let rsp = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("{}: {}; run tests/fetch-kats.sh", path, error));
+
+        rsp.split("\n\n")
+            .map(|record| {
+                record
+                    .lines()
+                    .filter_map(|line| line.split_once(" = "))
+                    .map(|(key, value)| (key.to_owned(), value.to_lowercase()))
+                    .collect::<HashMap<_, _>>()
+            })
+            .filter(|record| record.contains_key("count"))
+            .collect()
+    }
+
+    fn nist_kats<P: ParameterSet>(name: &str) {
+        let kats = load_rsp(name);
+        let count_seeds = kat_seeds(kats.len());
 
         for kat in kats {
-            println!("NIST KAT {}", kat.count);
+            let count: usize = kat["count"].parse().unwrap();
+            println!("NIST KAT {} {}", name, count);
 
-            let expected_sk_bytes = hex::decode(&kat.sk).unwrap();
-            assert_eq!(SecretKey::BYTES, expected_sk_bytes.len());
+            assert_eq!(hex::encode(count_seeds[count]), kat["seed"]);
 
-            let expected_pk_bytes = hex::decode(&kat.pk).unwrap();
-            assert_eq!(PublicKey::BYTES, expected_pk_bytes.len());
+            let expected_sk_bytes = hex::decode(&kat["sk"]).unwrap();
+            assert_eq!(SecretKey::<P>::BYTES, expected_sk_bytes.len());
 
-            let expected_ct_bytes = hex::decode(&kat.ct).unwrap();
-            assert_eq!(ClassicMcEliece::CIPHERTEXT_BYTES, expected_ct_bytes.len());
+            let expected_pk_bytes = hex::decode(&kat["pk"]).unwrap();
+            assert_eq!(PublicKey::<P>::BYTES, expected_pk_bytes.len());
 
-            let expected_ss_bytes = hex::decode(&kat.ss).unwrap();
-            assert_eq!(ClassicMcEliece::SESSION_KEY_BYTES, expected_ss_bytes.len());
+            let expected_ct_bytes = hex::decode(&kat["ct"]).unwrap();
+            assert_eq!(
+                ClassicMcEliece::<P>::CIPHERTEXT_BYTES,
+                expected_ct_bytes.len()
+            );
 
-            let expected_e_bytes = hex::decode(&kat.e).unwrap();
-            assert_eq!(ClassicMcEliece::N_BYTES, expected_e_bytes.len());
+            let expected_ss_bytes = hex::decode(&kat["ss"]).unwrap();
+            assert_eq!(
+                ClassicMcEliece::<P>::SESSION_KEY_BYTES,
+                expected_ss_bytes.len()
+            );
 
-            let seed = hex::decode(&kat.seed).unwrap();
+            // Key generation and then each attempt of FixedWeight draw from the DRBG of the count.
+            let mut drbg = CtrDrbg::new(&count_seeds[count]);
 
-            let (secret_key, public_key) =
-                ClassicMcEliece::generate_keypair_seeded(&seed.try_into().unwrap());
+            let mut seed = [0u8; L_BYTES];
+            drbg.fill(&mut seed);
 
-            {
-                let expected_sk = SecretKey::from_bytes(&expected_sk_bytes.try_into().unwrap());
+            let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair_from_seed(&seed);
 
+            let expected_sk = {
+                let mut bytes = P::SecretKeyBytes::zeroed();
+                bytes.as_mut().copy_from_slice(&expected_sk_bytes);
+                SecretKey::<P>::from_bytes_checked(&bytes).unwrap()
+            };
+
+            {
                 assert_eq!(secret_key.seed, expected_sk.seed);
+                assert_eq!(secret_key.pivots, expected_sk.pivots);
                 assert_eq!(secret_key.g, expected_sk.g);
                 assert_eq!(secret_key.control_bits, expected_sk.control_bits);
                 assert_eq!(secret_key.s, expected_sk.s);
 
-                assert_eq!(public_key.0, expected_pk_bytes[..]);
+                assert_eq!(public_key.as_ref(), &expected_pk_bytes[..]);
+
+                assert_eq!(secret_key.to_bytes().as_ref(), &expected_sk_bytes[..]);
+                assert_eq!(expected_sk.to_bytes().as_ref(), &expected_sk_bytes[..]);
             }
 
             {
+                let mut bytes = vec![0u8; SIGMA_ONE_BYTES * P::TAU];
+                let mut ind = [0u16; MAX_T];
+                loop {
+                    drbg.fill(&mut bytes);
+                    if bool::from(fixed_weight::<P>(&bytes, &mut ind[..P::T])) {
+                        break;
+                    }
+                }
+                let error = error_vector::<P>(&ind[..P::T]);
+
+                #[cfg(feature = "hazmat")]
+                let (ciphertext, session_key) = public_key
+                    .encapsulate_with_error_vector(&error[..P::N_BYTES])
+                    .unwrap();
+                #[cfg(not(feature = "hazmat"))]
                 let (ciphertext, session_key) =
-                    public_key.encapsulate_deterministic(&expected_e_bytes.try_into().unwrap());
+                    public_key.encapsulate_deterministic(&error[..P::N_BYTES]);
 
-                assert_eq!(ciphertext, expected_ct_bytes[..]);
-                assert_eq!(session_key, expected_ss_bytes[..]);
+                assert_eq!(ciphertext.as_ref(), &expected_ct_bytes[..]);
+                assert_eq!(session_key.as_ref(), &expected_ss_bytes[..]);
 
-                assert_eq!(secret_key.decapsulate(&ciphertext), expected_ss_bytes[..]);
+                assert_eq!(
+                    secret_key.decapsulate(&ciphertext).as_ref(),
+                    &expected_ss_bytes[..]
+                );
             }
         }
     }
+
+    // The KAT files of the NIST submission take over a gigabyte, so they are not in the tree;
+    // tests/fetch-kats.sh downloads them.
+    macro_rules! nist_kats {
+        ($($name:ident: $parameter_set:ty, $file:literal;)*) => {
+            $(
+                #[test]
+                #[ignore = "needs the NIST KAT files from tests/fetch-kats.sh"]
+                fn $name() {
+                    nist_kats::<$parameter_set>($file);
+                }
+            )*
+        };
+    }
+
+    nist_kats! {
+        nist_kats_mceliece348864: McEliece348864, "mceliece348864";
+        nist_kats_mceliece460896: McEliece460896, "mceliece460896";
+        nist_kats_mceliece6688128: McEliece6688128, "mceliece6688128";
+        nist_kats_mceliece6960119: McEliece6960119, "mceliece6960119";
+        nist_kats_mceliece8192128: McEliece8192128, "mceliece8192128";
+        nist_kats_mceliece348864f: McEliece348864f, "mceliece348864f";
+        nist_kats_mceliece460896f: McEliece460896f, "mceliece460896f";
+        nist_kats_mceliece6688128f: McEliece6688128f, "mceliece6688128f";
+        nist_kats_mceliece6960119f: McEliece6960119f, "mceliece6960119f";
+        nist_kats_mceliece8192128f: McEliece8192128f, "mceliece8192128f";
+    }
 }
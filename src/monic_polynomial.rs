@@ -1,24 +1,31 @@
-use std::convert::TryInto;
+use core::convert::TryInto;
 
-use subtle::{ConditionallySelectable, ConstantTimeEq};
+#[cfg(feature = "std")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::{Zeroize, Zeroizing};
 
-use crate::classic_mceliece::ClassicMcEliece;
+use crate::buffer::{Buffer, QBuffer};
+#[cfg(feature = "std")]
+use crate::ctgrind;
+use crate::fft::fft;
 use crate::field_element::FieldElement;
+use crate::parameter_set::{ParameterSet, MAX_T, SIGMA_ONE_BYTES};
 
 #[derive(Debug, Copy, Clone)]
-pub(crate) struct MonicPolynomial(pub(crate) [FieldElement; ClassicMcEliece::T]);
+pub(crate) struct MonicPolynomial<P: ParameterSet>(pub(crate) [FieldElement<P>; MAX_T]);
 
-impl MonicPolynomial {
-    pub(crate) const BYTES: usize = ClassicMcEliece::SIGMA_ONE_BYTES * ClassicMcEliece::T;
+impl<P: ParameterSet> MonicPolynomial<P> {
+    pub(crate) const BYTES: usize = SIGMA_ONE_BYTES * P::T;
 
     #[inline]
-    fn from_bytes_unchecked(bytes: &[u8; Self::BYTES]) -> Self {
-        let mut polynomial = Self([FieldElement::ZERO; ClassicMcEliece::T]);
+    fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), Self::BYTES);
 
-        for (limb, bytes) in polynomial
-            .0
+        let mut polynomial = Self([FieldElement::ZERO; MAX_T]);
+
+        for (limb, bytes) in polynomial.0[..P::T]
             .iter_mut()
-            .zip(bytes.chunks_exact(ClassicMcEliece::SIGMA_ONE_BYTES))
+            .zip(bytes.chunks_exact(SIGMA_ONE_BYTES))
         {
             *limb = FieldElement::from_le_bytes(bytes.try_into().unwrap());
         }
@@ -27,39 +34,52 @@ impl MonicPolynomial {
     }
 
     #[inline]
-    pub(crate) fn from_bytes(bytes: &[u8; Self::BYTES]) -> Option<Self> {
-        let polynomial = Self::from_bytes_unchecked(bytes);
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        debug_assert_eq!(bytes.len(), Self::BYTES);
 
-        if !polynomial.0.iter().all(|limb| limb.is_valid()) {
+        // Checked before `FieldElement::from_le_bytes` masks off the high bits:
+        if bytes.chunks_exact(SIGMA_ONE_BYTES).any(|limb| {
+            u16::from_le_bytes(limb.try_into().unwrap()) & !FieldElement::<P>::MASK != 0
+        }) {
             return None;
         }
 
-        Some(polynomial)
+        Some(Self::from_bytes_unchecked(bytes))
+    }
+
+    #[inline]
+    pub(crate) fn write_bytes(&self, output: &mut [u8]) {
+        debug_assert_eq!(output.len(), Self::BYTES);
+
+        for (bytes, limb) in output.chunks_exact_mut(SIGMA_ONE_BYTES).zip(self.0.iter()) {
+            bytes.copy_from_slice(&limb.to_le_bytes());
+        }
     }
 
-    pub(crate) fn irreducible(seed: &[u8; Self::BYTES]) -> Option<Self> {
-        debug_assert_eq!(ClassicMcEliece::SIGMA_ONE_BYTES, 2);
+    #[cfg(feature = "std")]
+    pub(crate) fn irreducible(seed: &[u8]) -> Option<Self> {
+        debug_assert_eq!(SIGMA_ONE_BYTES, 2);
 
-        let polynomial = Self::from_bytes_unchecked(seed);
+        let polynomial = Zeroizing::new(Self::from_bytes_unchecked(seed));
 
         let mut matrix =
-            [MonicPolynomial([FieldElement::ZERO; ClassicMcEliece::T]); ClassicMcEliece::T + 1];
+            Buffer::<Self, [Self; MAX_T + 1]>::new(Self([FieldElement::ZERO; MAX_T]), P::T + 1);
         matrix[0].0[0] = FieldElement::ONE;
-        matrix[1] = polynomial;
+        matrix[1] = *polynomial;
 
-        for i in 1..ClassicMcEliece::T {
-            let mut buffer = [FieldElement::ZERO; 2 * ClassicMcEliece::T - 1];
-            mul(&matrix[i], &polynomial, &mut buffer);
-            matrix[i + 1]
-                .0
-                .copy_from_slice(&buffer[..ClassicMcEliece::T]);
+        for i in 1..P::T {
+            let mut buffer = Zeroizing::new([FieldElement::ZERO; 2 * MAX_T - 1]);
+            mul(&matrix[i], &polynomial, &mut buffer[..(2 * P::T - 1)]);
+            matrix[i + 1].0[..P::T].copy_from_slice(&buffer[..P::T]);
         }
 
-        for j in 0..ClassicMcEliece::T {
-            for k in (j + 1)..ClassicMcEliece::T {
-                // TODO: Use is_zero_mask?
+        // A zero pivot only sets `singular`, so the work done is independent of the seed.
+        let mut singular = Choice::from(0);
+
+        for j in 0..P::T {
+            for k in (j + 1)..P::T {
                 let jj_is_zero = matrix[j].0[j].ct_eq(&FieldElement::ZERO);
-                for row in matrix.iter_mut().take(ClassicMcEliece::T + 1).skip(j) {
+                for row in matrix.iter_mut().skip(j) {
                     row.0[j] += FieldElement::conditional_select(
                         &FieldElement::ZERO,
                         &row.0[k],
@@ -68,107 +88,126 @@ impl MonicPolynomial {
                 }
             }
 
-            if matrix[j].0[j].vartime_is_zero() {
-                return None;
-            }
+            let jj_is_zero = matrix[j].0[j].ct_eq(&FieldElement::ZERO);
+            singular |= jj_is_zero;
 
-            let inverse = matrix[j].0[j].inverse();
+            let mut jj = matrix[j].0[j];
+            jj.conditional_assign(&FieldElement::ONE, jj_is_zero);
+            let inverse = jj.inverse();
 
-            for row in matrix.iter_mut().take(ClassicMcEliece::T + 1).skip(j) {
+            for row in matrix.iter_mut().skip(j) {
                 row.0[j] *= inverse;
             }
 
-            for k in (0..ClassicMcEliece::T).filter(|k| *k != j) {
+            for k in (0..P::T).filter(|k| *k != j) {
                 let jk = matrix[j].0[k];
-                for row in matrix.iter_mut().take(ClassicMcEliece::T + 1).skip(j) {
+                for row in matrix.iter_mut().skip(j) {
                     row.0[k] += row.0[j] * jk;
                 }
             }
         }
 
-        Some(*matrix.last().unwrap())
+        ctgrind::unpoison(&singular);
+
+        if bool::from(singular) {
+            return None;
+        }
+
+        Some(matrix[P::T])
     }
 
     #[inline]
-    pub(crate) fn reversing_explicitly_monic(
-        polynomial: &[FieldElement; ClassicMcEliece::T + 1],
-    ) -> Self {
+    pub(crate) fn reversing_explicitly_monic(polynomial: &[FieldElement<P>]) -> Self {
+        debug_assert_eq!(polynomial.len(), P::T + 1);
         // debug_assert!(polynomial[0], FieldElement::ONE);
 
-        let mut reversed = Self([FieldElement::ZERO; ClassicMcEliece::T]);
+        let mut reversed = Self([FieldElement::ZERO; MAX_T]);
 
-        for (r_limb, limb) in reversed.0.iter_mut().zip(polynomial.iter().rev()) {
+        for (r_limb, limb) in reversed.0[..P::T].iter_mut().zip(polynomial.iter().rev()) {
             *r_limb = *limb;
         }
 
         reversed
     }
 
-    pub(crate) fn evaluate_at(&self, a: FieldElement) -> FieldElement {
-        self.0
+    #[cfg(all(test, feature = "std"))]
+    pub(crate) fn evaluate_at(&self, a: FieldElement<P>) -> FieldElement<P> {
+        self.0[..P::T]
             .iter()
             .rev()
             .skip(1)
-            .fold(a + *self.0.last().unwrap(), |r, limb| r * a + *limb)
+            .fold(a + self.0[P::T - 1], |r, limb| r * a + *limb)
     }
 
-    #[inline]
-    pub(crate) fn root(
-        &self,
-        support: &[FieldElement; ClassicMcEliece::N],
-    ) -> [FieldElement; ClassicMcEliece::N] {
-        let mut output = [FieldElement::ZERO; ClassicMcEliece::N];
+    // Evaluates at every field element, in the bit-reversed order of `fft`.
+    pub(crate) fn root(&self) -> QBuffer<P, FieldElement<P>> {
+        let mut coefficients = Zeroizing::new([FieldElement::ONE; MAX_T + 1]);
+        coefficients[..P::T].copy_from_slice(&self.0[..P::T]);
 
-        for (output, support) in output.iter_mut().zip(support.iter()) {
-            *output = self.evaluate_at(*support);
-        }
+        let mut images = Buffer::new(FieldElement::ZERO, P::Q);
+        fft(&coefficients[..=P::T], &mut images);
+        images
+    }
+}
 
-        output
+impl<P: ParameterSet> Zeroize for MonicPolynomial<P> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
     }
 }
 
-fn mul(
-    lhs: &MonicPolynomial,
-    rhs: &MonicPolynomial,
-    buffer: &mut [FieldElement; 2 * ClassicMcEliece::T - 1],
+#[cfg(feature = "std")]
+fn mul<P: ParameterSet>(
+    lhs: &MonicPolynomial<P>,
+    rhs: &MonicPolynomial<P>,
+    buffer: &mut [FieldElement<P>],
 ) {
+    debug_assert_eq!(buffer.len(), 2 * P::T - 1);
+
     #[cfg(test)]
     assert!(buffer.iter().all(|x| x == &0));
 
-    for (i, lhs) in lhs.0.iter().enumerate() {
-        for (j, rhs) in rhs.0.iter().enumerate() {
+    for (i, lhs) in lhs.0[..P::T].iter().enumerate() {
+        for (j, rhs) in rhs.0[..P::T].iter().enumerate() {
             buffer[i + j] += *lhs * *rhs;
         }
     }
 
-    for i in (ClassicMcEliece::T..buffer.len()).rev() {
+    for i in (P::T..buffer.len()).rev() {
         let limb = buffer[i];
-        buffer[i - ClassicMcEliece::T + 3] += limb;
-        buffer[i - ClassicMcEliece::T + 1] += limb;
-        buffer[i - ClassicMcEliece::T + 0] += limb * FieldElement::TWO;
+        for (degree, coefficient) in P::GOPPA_POLYNOMIAL {
+            buffer[i - P::T + degree] += limb * FieldElement::from(*coefficient);
+        }
     }
 }
 
 #[cfg(test)]
-impl PartialEq<MonicPolynomial> for MonicPolynomial {
-    fn eq(&self, other: &MonicPolynomial) -> bool {
+impl<P: ParameterSet> PartialEq<MonicPolynomial<P>> for MonicPolynomial<P> {
+    fn eq(&self, other: &MonicPolynomial<P>) -> bool {
         self.0 == other.0
     }
 }
 
 #[cfg(test)]
-impl PartialEq<[u16; ClassicMcEliece::T]> for MonicPolynomial {
-    fn eq(&self, other: &[u16; ClassicMcEliece::T]) -> bool {
-        self.0.iter().zip(other).all(|(lhs, rhs)| lhs == rhs)
+impl<P: ParameterSet> PartialEq<[u16]> for MonicPolynomial<P> {
+    fn eq(&self, other: &[u16]) -> bool {
+        other.len() == P::T && self.0.iter().zip(other).all(|(lhs, rhs)| lhs == rhs)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use hex_literal::hex;
+    use proptest::prelude::*;
+    use rand::Rng;
 
+    use crate::field_element::FieldElement;
     use crate::generator::Generator;
     use crate::monic_polynomial::MonicPolynomial;
+    use crate::parameter_set::{
+        McEliece348864, McEliece6960119, McEliece8192128, ParameterSet, MAX_T,
+    };
 
     #[test]
     fn irreducible() {
@@ -179,10 +218,10 @@ mod tests {
 
         generator.skip(436 + 4 * 4096);
 
-        let mut seed = [0u8; MonicPolynomial::BYTES];
+        let mut seed = [0u8; MonicPolynomial::<McEliece348864>::BYTES];
         generator.squeeze(&mut seed);
 
-        let g = MonicPolynomial::irreducible(&seed).unwrap();
+        let g = MonicPolynomial::<McEliece348864>::irreducible(&seed).unwrap();
 
         assert_eq!(
             g,
@@ -193,7 +232,184 @@ mod tests {
                 0x061, 0x86c, 0x538, 0xf8a, 0x05b, 0xf94, 0xa3a, 0x581, 0x2c5, 0xde4, 0xddf, 0x068,
                 0xd8e, 0xdba, 0x855, 0x69c, 0x9e5, 0x849, 0x5e1, 0x7b6, 0x92c, 0x499, 0x1e7, 0xf98,
                 0xa6c, 0xda5, 0x690, 0xd51,
-            ]
+            ][..]
         );
     }
+
+    #[test]
+    fn root() {
+        type P = McEliece348864;
+
+        let mut g = MonicPolynomial::<P>([FieldElement::ZERO; MAX_T]);
+        for x in g.0[..P::T].iter_mut() {
+            *x = rand::thread_rng().gen::<u16>().into();
+        }
+
+        for (x, image) in g.root().iter().enumerate() {
+            let a = FieldElement::<P>::from(x as u16).reverse_bits();
+            assert_eq!(*image, g.evaluate_at(a));
+        }
+    }
+
+    // Polynomials below are coefficient vectors, lowest degree first, without leading zeros.
+
+    fn trim<P: ParameterSet>(mut a: Vec<FieldElement<P>>) -> Vec<FieldElement<P>> {
+        while a.last() == Some(&FieldElement::ZERO) {
+            a.pop();
+        }
+        a
+    }
+
+    fn rem<P: ParameterSet>(
+        mut a: Vec<FieldElement<P>>,
+        b: &[FieldElement<P>],
+    ) -> Vec<FieldElement<P>> {
+        let inverse = b.last().unwrap().inverse();
+
+        while a.len() >= b.len() {
+            let factor = *a.last().unwrap() * inverse;
+            let offset = a.len() - b.len();
+            for (a, b) in a[offset..].iter_mut().zip(b.iter()) {
+                *a += factor * *b;
+            }
+            a.pop();
+            a = trim(a);
+        }
+
+        a
+    }
+
+    fn gcd<P: ParameterSet>(
+        a: Vec<FieldElement<P>>,
+        b: Vec<FieldElement<P>>,
+    ) -> Vec<FieldElement<P>> {
+        if b.is_empty() {
+            a
+        } else {
+            let r = rem(a, &b);
+            gcd(b, r)
+        }
+    }
+
+    // Raises `a` to the power Q modulo `g`, by squaring M times.
+    fn frobenius<P: ParameterSet>(
+        mut a: Vec<FieldElement<P>>,
+        g: &[FieldElement<P>],
+    ) -> Vec<FieldElement<P>> {
+        for _ in 0..P::M {
+            let mut square = vec![FieldElement::ZERO; 2 * a.len()];
+            for (i, limb) in a.iter().enumerate() {
+                square[2 * i] = limb.square();
+            }
+            a = rem(trim(square), g);
+        }
+        a
+    }
+
+    // Rabin's test: g of degree n is irreducible over GF(Q) if and only if y^(Q^n) = y modulo g
+    // and gcd(g, y^(Q^(n/p)) - y) = 1 for every prime p dividing n.
+    fn is_irreducible<P: ParameterSet>(g: &[FieldElement<P>]) -> bool {
+        let n = g.len() - 1;
+        let y = vec![FieldElement::ZERO, FieldElement::ONE];
+
+        let primes: Vec<usize> = (2..=n)
+            .filter(|p| n.is_multiple_of(*p) && (2..*p).all(|d| !p.is_multiple_of(d)))
+            .collect();
+
+        let mut power = y.clone(); // y^(Q^k)
+
+        for k in 1..=n {
+            power = frobenius(power, g);
+
+            if primes.iter().any(|p| k == n / p) {
+                let mut difference = power.clone();
+                if difference.len() < 2 {
+                    difference.resize(2, FieldElement::ZERO);
+                }
+                difference[1] += FieldElement::ONE;
+                if gcd(g.to_vec(), trim(difference)).len() != 1 {
+                    return false;
+                }
+            }
+        }
+
+        power == y
+    }
+
+    fn irreducible_is_irreducible<P: ParameterSet>(seed: &[u8]) {
+        if let Some(g) = MonicPolynomial::<P>::irreducible(seed) {
+            assert!(g.0[P::T..].iter().all(|limb| *limb == FieldElement::ZERO));
+
+            let mut coefficients = g.0[..P::T].to_vec();
+            coefficients.push(FieldElement::ONE);
+            assert!(is_irreducible(&coefficients));
+        }
+    }
+
+    fn naive_evaluate_at<P: ParameterSet>(coefficients: &[u16], a: u16) {
+        let mut g = MonicPolynomial::<P>([FieldElement::ZERO; MAX_T]);
+        for (limb, coefficient) in g.0.iter_mut().zip(coefficients.iter()) {
+            *limb = (*coefficient).into();
+        }
+
+        let a = FieldElement::<P>::from(a);
+
+        let mut expected = FieldElement::ZERO;
+        let mut power = FieldElement::ONE;
+        for limb in g.0[..P::T].iter() {
+            expected += *limb * power;
+            power *= a;
+        }
+        expected += power;
+
+        assert_eq!(g.evaluate_at(a), expected);
+    }
+
+    #[test]
+    fn is_irreducible_rejects_products() {
+        type P = McEliece348864;
+
+        let (zero, one) = (FieldElement::<P>::ZERO, FieldElement::<P>::ONE);
+        let a = FieldElement::<P>::from(0x123);
+
+        // (y + 1)(y^2 + y + a) and y(y + 1):
+        assert!(!is_irreducible(&[a, a + one, zero, one]));
+        assert!(!is_irreducible(&[zero, one, one]));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(4))]
+
+        #[test]
+        fn irreducible_is_irreducible_mceliece348864(
+            seed in proptest::collection::vec(any::<u8>(), MonicPolynomial::<McEliece348864>::BYTES),
+        ) {
+            irreducible_is_irreducible::<McEliece348864>(&seed);
+        }
+
+        #[test]
+        fn irreducible_is_irreducible_mceliece6960119(
+            seed in proptest::collection::vec(any::<u8>(), MonicPolynomial::<McEliece6960119>::BYTES),
+        ) {
+            irreducible_is_irreducible::<McEliece6960119>(&seed);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn evaluate_at_mceliece348864(
+            coefficients in proptest::collection::vec(any::<u16>(), McEliece348864::T),
+            a: u16,
+        ) {
+            naive_evaluate_at::<McEliece348864>(&coefficients, a);
+        }
+
+        #[test]
+        fn evaluate_at_mceliece8192128(
+            coefficients in proptest::collection::vec(any::<u16>(), McEliece8192128::T),
+            a: u16,
+        ) {
+            naive_evaluate_at::<McEliece8192128>(&coefficients, a);
+        }
+    }
 }
@@ -0,0 +1,321 @@
+#[cfg(feature = "std")]
+use core::convert::TryInto;
+use core::fmt::Debug;
+
+pub(crate) const L: usize = 256;
+pub(crate) const L_BYTES: usize = L / 8;
+
+pub(crate) const SIGMA_ONE_BYTES: usize = 2;
+#[cfg(feature = "std")]
+pub(crate) const SIGMA_TWO_BYTES: usize = 4;
+
+// Upper bounds over all parameter sets, used to size working buffers:
+
+pub(crate) const MAX_M: usize = 13;
+pub(crate) const MAX_N: usize = 8192;
+pub(crate) const MAX_T: usize = 128;
+
+pub(crate) const MAX_Q: usize = 1 << MAX_M;
+pub(crate) const MAX_N_BYTES: usize = MAX_N.div_ceil(8);
+
+/// A Classic McEliece parameter set. The trait is sealed: only the ten sets of this crate
+/// implement it, and keys and ciphertexts of different sets are different types.
+pub trait ParameterSet: sealed::Sealed + Clone + Copy + Debug + 'static {
+    const M: usize;
+    const N: usize;
+    const T: usize;
+
+    // Lower-order terms of the field polynomial, i.e. f(z) - z^M.
+    const FIELD_POLYNOMIAL: u16;
+
+    // Lower-order terms (degree, coefficient) of F(y) - y^T.
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)];
+
+    type SecretKeyBytes: ByteArray;
+    type PublicKeyBytes: ByteArray;
+    type CiphertextBytes: ByteArray;
+
+    // Whether key generation uses the (mu, nu) = (32, 64) semi-systematic form of the "f" variants.
+    const SEMI_SYSTEMATIC: bool = false;
+
+    const Q: usize = 1 << Self::M;
+    const K: usize = Self::N - Self::M * Self::T;
+
+    // Number of candidate indices drawn by FixedWeight; no rejection is needed when N == Q.
+    const TAU: usize = if Self::N == Self::Q {
+        Self::T
+    } else {
+        2 * Self::T
+    };
+
+    const N_BYTES: usize = Self::N.div_ceil(8);
+    const K_BYTES: usize = Self::K.div_ceil(8);
+
+    const C0_BYTES: usize = (Self::M * Self::T).div_ceil(8);
+    const C1_BYTES: usize = L_BYTES;
+    const CIPHERTEXT_BYTES: usize = Self::C0_BYTES + Self::C1_BYTES;
+}
+
+pub(crate) mod sealed {
+    use crate::buffer::Array;
+
+    // Arrays of Q, 2Q and N elements, the storage of the working buffers without `std`.
+    pub trait Sealed {
+        type QArray<T: Copy>: Array<T>;
+        type DoubleQArray<T: Copy>: Array<T>;
+        type NArray<T: Copy>: Array<T>;
+    }
+}
+
+/// The byte arrays of a parameter set, such as `P::SecretKeyBytes`, which `zeroed` builds
+/// generically.
+pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + Copy + Debug + PartialEq {
+    fn zeroed() -> Self;
+
+    // Allocates directly on the heap, since public keys are too large for small stacks.
+    #[cfg(feature = "std")]
+    fn zeroed_boxed() -> Box<Self>;
+}
+
+impl<const SIZE: usize> ByteArray for [u8; SIZE] {
+    #[inline]
+    fn zeroed() -> Self {
+        [0; SIZE]
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn zeroed_boxed() -> Box<Self> {
+        vec![0; SIZE].into_boxed_slice().try_into().unwrap()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece348864;
+
+impl sealed::Sealed for McEliece348864 {
+    type QArray<T: Copy> = [T; 4096];
+    type DoubleQArray<T: Copy> = [T; 8192];
+    type NArray<T: Copy> = [T; 3488];
+}
+
+impl ParameterSet for McEliece348864 {
+    const M: usize = 12;
+    const N: usize = 3488;
+    const T: usize = 64;
+
+    const FIELD_POLYNOMIAL: u16 = 0b1001;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(3, 1), (1, 1), (0, 2)];
+
+    type SecretKeyBytes = [u8; 6492];
+    type PublicKeyBytes = [u8; 261120];
+    type CiphertextBytes = [u8; 128];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece460896;
+
+impl sealed::Sealed for McEliece460896 {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 4608];
+}
+
+impl ParameterSet for McEliece460896 {
+    const M: usize = 13;
+    const N: usize = 4608;
+    const T: usize = 96;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(10, 1), (9, 1), (6, 1), (0, 1)];
+
+    type SecretKeyBytes = [u8; 13608];
+    type PublicKeyBytes = [u8; 524160];
+    type CiphertextBytes = [u8; 188];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece6688128;
+
+impl sealed::Sealed for McEliece6688128 {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 6688];
+}
+
+impl ParameterSet for McEliece6688128 {
+    const M: usize = 13;
+    const N: usize = 6688;
+    const T: usize = 128;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(7, 1), (2, 1), (1, 1), (0, 1)];
+
+    type SecretKeyBytes = [u8; 13932];
+    type PublicKeyBytes = [u8; 1044992];
+    type CiphertextBytes = [u8; 240];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece6960119;
+
+impl sealed::Sealed for McEliece6960119 {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 6960];
+}
+
+impl ParameterSet for McEliece6960119 {
+    const M: usize = 13;
+    const N: usize = 6960;
+    const T: usize = 119;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(8, 1), (0, 1)];
+
+    type SecretKeyBytes = [u8; 13948];
+    type PublicKeyBytes = [u8; 1047319];
+    type CiphertextBytes = [u8; 226];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece8192128;
+
+impl sealed::Sealed for McEliece8192128 {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 8192];
+}
+
+impl ParameterSet for McEliece8192128 {
+    const M: usize = 13;
+    const N: usize = 8192;
+    const T: usize = 128;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(7, 1), (2, 1), (1, 1), (0, 1)];
+
+    type SecretKeyBytes = [u8; 14120];
+    type PublicKeyBytes = [u8; 1357824];
+    type CiphertextBytes = [u8; 240];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece348864f;
+
+impl sealed::Sealed for McEliece348864f {
+    type QArray<T: Copy> = [T; 4096];
+    type DoubleQArray<T: Copy> = [T; 8192];
+    type NArray<T: Copy> = [T; 3488];
+}
+
+impl ParameterSet for McEliece348864f {
+    const M: usize = 12;
+    const N: usize = 3488;
+    const T: usize = 64;
+
+    const FIELD_POLYNOMIAL: u16 = 0b1001;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(3, 1), (1, 1), (0, 2)];
+
+    const SEMI_SYSTEMATIC: bool = true;
+
+    type SecretKeyBytes = [u8; 6492];
+    type PublicKeyBytes = [u8; 261120];
+    type CiphertextBytes = [u8; 128];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece460896f;
+
+impl sealed::Sealed for McEliece460896f {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 4608];
+}
+
+impl ParameterSet for McEliece460896f {
+    const M: usize = 13;
+    const N: usize = 4608;
+    const T: usize = 96;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(10, 1), (9, 1), (6, 1), (0, 1)];
+
+    const SEMI_SYSTEMATIC: bool = true;
+
+    type SecretKeyBytes = [u8; 13608];
+    type PublicKeyBytes = [u8; 524160];
+    type CiphertextBytes = [u8; 188];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece6688128f;
+
+impl sealed::Sealed for McEliece6688128f {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 6688];
+}
+
+impl ParameterSet for McEliece6688128f {
+    const M: usize = 13;
+    const N: usize = 6688;
+    const T: usize = 128;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(7, 1), (2, 1), (1, 1), (0, 1)];
+
+    const SEMI_SYSTEMATIC: bool = true;
+
+    type SecretKeyBytes = [u8; 13932];
+    type PublicKeyBytes = [u8; 1044992];
+    type CiphertextBytes = [u8; 240];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece6960119f;
+
+impl sealed::Sealed for McEliece6960119f {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 6960];
+}
+
+impl ParameterSet for McEliece6960119f {
+    const M: usize = 13;
+    const N: usize = 6960;
+    const T: usize = 119;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(8, 1), (0, 1)];
+
+    const SEMI_SYSTEMATIC: bool = true;
+
+    type SecretKeyBytes = [u8; 13948];
+    type PublicKeyBytes = [u8; 1047319];
+    type CiphertextBytes = [u8; 226];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct McEliece8192128f;
+
+impl sealed::Sealed for McEliece8192128f {
+    type QArray<T: Copy> = [T; 8192];
+    type DoubleQArray<T: Copy> = [T; 16384];
+    type NArray<T: Copy> = [T; 8192];
+}
+
+impl ParameterSet for McEliece8192128f {
+    const M: usize = 13;
+    const N: usize = 8192;
+    const T: usize = 128;
+
+    const FIELD_POLYNOMIAL: u16 = 0b11011;
+    const GOPPA_POLYNOMIAL: &'static [(usize, u16)] = &[(7, 1), (2, 1), (1, 1), (0, 1)];
+
+    const SEMI_SYSTEMATIC: bool = true;
+
+    type SecretKeyBytes = [u8; 14120];
+    type PublicKeyBytes = [u8; 1357824];
+    type CiphertextBytes = [u8; 240];
+}
@@ -1,37 +1,63 @@
-use std::convert::TryInto;
-
-use crate::classic_mceliece::ClassicMcEliece;
+use core::borrow::{Borrow, BorrowMut};
+use core::convert::{TryFrom, TryInto};
+
+use rand_core::{CryptoRng, RngCore};
+use subtle::Choice;
+#[cfg(feature = "std")]
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use crate::backend;
+use crate::ciphertext::Ciphertext;
+use crate::ctgrind;
+use crate::error::Error;
 use crate::field_element::FieldElement;
+#[cfg(feature = "std")]
 use crate::field_ordering::FieldOrdering;
+#[cfg(feature = "std")]
+use crate::field_vector::invert;
 use crate::generator::Generator;
+#[cfg(feature = "std")]
 use crate::monic_polynomial::MonicPolynomial;
+use crate::parameter_set::{ByteArray, ParameterSet, L_BYTES, MAX_N_BYTES, MAX_T, SIGMA_ONE_BYTES};
+use crate::session_key::SessionKey;
+
+// Public keys are too large for small thread stacks, so with `std` they live on the heap.
+#[cfg(feature = "std")]
+type Bytes<P> = Box<<P as ParameterSet>::PublicKeyBytes>;
+#[cfg(not(feature = "std"))]
+type Bytes<P> = <P as ParameterSet>::PublicKeyBytes;
 
-pub struct PublicKey(pub(crate) [u8; PublicKey::BYTES]);
+/// A public key. With `std` its bytes, of up to 1.3 MB, live on the heap; without it they are
+/// held by value.
+pub struct PublicKey<P: ParameterSet>(pub(crate) Bytes<P>);
 
-impl PublicKey {
-    const ROWS: usize = ClassicMcEliece::M * ClassicMcEliece::T;
-    const ROW_BYTES: usize = ClassicMcEliece::K_BYTES;
+impl<P: ParameterSet> PublicKey<P> {
+    const ROWS: usize = P::M * P::T;
+    const ROW_BYTES: usize = P::K_BYTES;
 
     pub const BYTES: usize = Self::ROWS * Self::ROW_BYTES;
 
-    pub(crate) fn generate(g: &MonicPolynomial, alpha: &FieldOrdering) -> Option<Self> {
-        let mut matrix = [[0u8; ClassicMcEliece::N_BYTES]; PublicKey::ROWS];
+    // Returns the public key together with the pivot positions of the semi-systematic form.
+    #[cfg(feature = "std")]
+    pub(crate) fn generate(
+        g: &MonicPolynomial<P>,
+        alpha: &mut FieldOrdering<P>,
+    ) -> Option<(Self, u64)> {
+        let mut matrix = Zeroizing::new(vec![[0u8; MAX_N_BYTES]; Self::ROWS]);
 
         {
             // Filling the matrix:
 
             let support = alpha.generate_support();
 
-            let mut inv = g.root(&support);
-
-            for inv in inv.iter_mut() {
-                *inv = inv.inverse();
-            }
+            let mut inv = alpha.to_support_order(&g.root());
+            invert(&mut inv);
 
-            for i in 0..ClassicMcEliece::T {
+            for i in 0..P::T {
                 for (j, inv) in inv.chunks_exact(8).enumerate() {
-                    for k in 0..ClassicMcEliece::M {
-                        matrix[i * ClassicMcEliece::M + k][j] = inv
+                    for k in 0..P::M {
+                        matrix[i * P::M + k][j] = inv
                             .iter()
                             .rev()
                             .map(|inv| inv.get_bit(k))
@@ -45,120 +71,192 @@ impl PublicKey {
             }
         }
 
-        // Gaussian elimination:
+        // Gaussian elimination, which runs to completion even when the matrix turns out to be
+        // singular:
 
-        for i in 0..(PublicKey::ROWS / 8) {
+        let mut pivots = 0xffff_ffff;
+        let mut singular = Choice::from(0);
+
+        for i in 0..Self::ROWS.div_ceil(8) {
             for j in 0..8 {
                 let row = i * 8 + j;
 
-                if row >= PublicKey::ROWS {
+                if row >= Self::ROWS {
                     break;
                 }
 
-                for k in (row + 1)..PublicKey::ROWS {
-                    let mut mask = matrix[row][i] ^ matrix[k][i];
-                    mask >>= j;
-                    mask &= 1;
-                    mask = mask.wrapping_neg();
+                if P::SEMI_SYSTEMATIC && row == Self::ROWS - MU {
+                    let (moved_pivots, moved_singular) = move_columns(&mut matrix, alpha);
+                    pivots = moved_pivots;
+                    singular |= moved_singular;
+                }
+
+                {
+                    let (pivot, others) = matrix.split_at_mut(row + 1);
+                    let pivot = &mut pivot[row][..P::N_BYTES];
+
+                    for other in others.iter() {
+                        let mut mask = pivot[i] ^ other[i];
+                        mask >>= j;
+                        mask &= 1;
+                        mask = mask.wrapping_neg();
 
-                    for c in 0..ClassicMcEliece::N_BYTES {
-                        matrix[row][c] ^= matrix[k][c] & mask;
+                        backend::xor_masked(pivot, &other[..P::N_BYTES], mask);
                     }
                 }
 
-                if ((matrix[row][i] >> j) & 0b1) == 0 {
-                    return None;
-                }
+                singular |= Choice::from(((matrix[row][i] >> j) & 0b1) ^ 1);
+
+                let pivot = Zeroizing::new(matrix[row]);
 
-                for k in (0..PublicKey::ROWS).filter(|k| *k != row) {
-                    let mut mask = matrix[k][i] >> j;
+                for (_, other) in matrix.iter_mut().enumerate().filter(|(k, _)| *k != row) {
+                    let mut mask = other[i] >> j;
                     mask &= 1;
                     mask = mask.wrapping_neg();
 
-                    for c in 0..ClassicMcEliece::N_BYTES {
-                        matrix[k][c] ^= matrix[row][c] & mask;
-                    }
+                    backend::xor_masked(&mut other[..P::N_BYTES], &pivot[..P::N_BYTES], mask);
                 }
             }
         }
 
-        let mut public_key = PublicKey([0; Self::BYTES]);
+        ctgrind::unpoison(&singular);
 
-        for (pk_row, matrix_row) in public_key
-            .0
-            .chunks_exact_mut(PublicKey::ROW_BYTES)
+        if bool::from(singular) {
+            return None;
+        }
+
+        let mut public_key = Self(P::PublicKeyBytes::zeroed_boxed());
+
+        for (pk_row, matrix_row) in (*public_key.0)
+            .as_mut()
+            .chunks_exact_mut(Self::ROW_BYTES)
             .zip(matrix.iter())
         {
-            pk_row.copy_from_slice(&matrix_row[(PublicKey::ROWS / 8)..]); // TODO: Ok?
+            copy_from_bit(pk_row, &matrix_row[..P::N_BYTES], Self::ROWS);
         }
 
-        Some(public_key)
+        Some((public_key, pivots))
     }
 
+    /// Parses a public key, rejecting rows whose padding bits are not zero.
     #[inline]
-    // TODO: Test!
-    pub fn encapsulate(
-        &self,
-    ) -> (
-        [u8; ClassicMcEliece::CIPHERTEXT_BYTES],
-        [u8; ClassicMcEliece::SESSION_KEY_BYTES],
-    ) {
-        use rand::RngCore;
+    pub fn from_bytes(input: &P::PublicKeyBytes) -> Result<Self, Error> {
+        Self::try_from(input.as_ref())
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &P::PublicKeyBytes {
+        self.0.borrow()
+    }
+
+    /// Copies the bytes of the public key, into a `Box` since they are too large for small stacks.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_bytes(&self) -> Box<P::PublicKeyBytes> {
+        let mut bytes = P::PublicKeyBytes::zeroed_boxed();
+        (*bytes).as_mut().copy_from_slice(self.as_ref());
+        bytes
+    }
+
+    /// Encapsulates a fresh session key, drawing the encapsulation seed from `rand::thread_rng`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn encapsulate(&self) -> (Ciphertext<P>, SessionKey) {
+        self.encapsulate_with_rng(&mut rand::thread_rng())
+    }
 
-        let mut seed = [0u8; ClassicMcEliece::L_BYTES];
-        rand::thread_rng().fill_bytes(&mut seed);
+    /// Encapsulates a fresh session key, drawing the encapsulation seed from `rng`.
+    ///
+    /// The error vector is drawn by FixedWeight in constant time: each attempt filters its
+    /// candidate indices and checks them for repetition, and only whether it is retried is
+    /// revealed.
+    #[inline]
+    pub fn encapsulate_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+    ) -> (Ciphertext<P>, SessionKey) {
+        let mut seed = Zeroizing::new([0u8; L_BYTES]);
+        rng.fill_bytes(&mut *seed);
 
-        let error = seeded_fixed_weight(&seed);
+        self.encapsulate_seeded(&seed)
+    }
 
-        self.encapsulate_deterministic(&error)
+    /// Deterministic encapsulation for reproducing known answers; the seed must never be reused.
+    #[cfg(feature = "hazmat")]
+    #[inline]
+    pub fn encapsulate_from_seed(&self, seed: &[u8; L_BYTES]) -> (Ciphertext<P>, SessionKey) {
+        self.encapsulate_seeded(seed)
     }
 
-    pub(crate) fn encapsulate_deterministic(
+    /// Encapsulates a caller-chosen error vector, which must have weight T and never be reused.
+    /// Any other error vector would give a ciphertext that does not decapsulate, so it is rejected
+    /// with `Error::InvalidWeight`.
+    #[cfg(feature = "hazmat")]
+    pub fn encapsulate_with_error_vector(
         &self,
-        error: &[u8; ClassicMcEliece::N_BYTES],
-    ) -> (
-        [u8; ClassicMcEliece::CIPHERTEXT_BYTES],
-        [u8; ClassicMcEliece::SESSION_KEY_BYTES],
-    ) {
+        error: &[u8],
+    ) -> Result<(Ciphertext<P>, SessionKey), Error> {
+        if error.len() != P::N_BYTES {
+            return Err(Error::InvalidLength);
+        }
+
+        let weight: u32 = error.iter().map(|byte| byte.count_ones()).sum();
+        if weight != P::T as u32 {
+            return Err(Error::InvalidWeight);
+        }
+
+        Ok(self.encapsulate_deterministic(error))
+    }
+
+    fn encapsulate_seeded(&self, seed: &[u8; L_BYTES]) -> (Ciphertext<P>, SessionKey) {
+        ctgrind::poison(seed);
+
+        let error = seeded_fixed_weight::<P>(seed);
+        let (ciphertext, session_key) = self.encapsulate_deterministic(&error[..P::N_BYTES]);
+
+        ctgrind::unpoison(seed);
+        ctgrind::unpoison(&ciphertext);
+        ctgrind::unpoison(&session_key);
+
+        (ciphertext, session_key)
+    }
+
+    pub(crate) fn encapsulate_deterministic(&self, error: &[u8]) -> (Ciphertext<P>, SessionKey) {
         use crate::hash::*;
 
-        let mut ciphertext = [0u8; ClassicMcEliece::CIPHERTEXT_BYTES];
+        debug_assert_eq!(error.len(), P::N_BYTES);
 
-        let c0 = &mut ciphertext[..ClassicMcEliece::C0_BYTES];
-        self.encode(error, c0.try_into().unwrap());
+        let mut ciphertext = Ciphertext::<P>(P::CiphertextBytes::zeroed());
 
-        let c1 = &mut ciphertext[ClassicMcEliece::C0_BYTES..];
+        let c0 = &mut ciphertext.0.as_mut()[..P::C0_BYTES];
+        self.encode(error, c0);
+
+        let c1 = &mut ciphertext.0.as_mut()[P::C0_BYTES..];
         hash_2(error, c1.try_into().unwrap());
 
-        let mut session_key = [0u8; ClassicMcEliece::SESSION_KEY_BYTES];
-        hash_x(1, error, &ciphertext, &mut session_key);
+        let mut session_key = SessionKey::zeroed();
+        hash_x(1, error, ciphertext.as_ref(), &mut session_key.0);
 
         (ciphertext, session_key)
     }
 
-    fn encode(
-        &self,
-        error: &[u8; ClassicMcEliece::N_BYTES],
-        syndrome: &mut [u8; ClassicMcEliece::C0_BYTES],
-    ) {
+    fn encode(&self, error: &[u8], syndrome: &mut [u8]) {
         debug_assert!(syndrome.iter().all(|s| *s == 0));
 
-        let lhs_error = &error[..(ClassicMcEliece::N_BYTES - Self::ROW_BYTES)];
-        let rhs_error = &error[(ClassicMcEliece::N_BYTES - Self::ROW_BYTES)..];
+        let lhs_error = &error[..P::C0_BYTES];
+
+        let mut rhs_error = Zeroizing::new([0u8; MAX_N_BYTES]);
+        let rhs_error = &mut rhs_error[..Self::ROW_BYTES];
+        copy_from_bit(rhs_error, error, Self::ROWS);
 
         for ((syndrome_byte, lhs_error_byte), eight_rows) in syndrome
             .iter_mut()
             .zip(lhs_error)
-            .zip(self.0.chunks_exact(8 * Self::ROW_BYTES))
+            .zip(self.as_ref().chunks(8 * Self::ROW_BYTES))
         {
             for (selected_bit, row) in eight_rows.chunks_exact(Self::ROW_BYTES).enumerate() {
                 let byte = (1 << selected_bit) & lhs_error_byte;
-
-                let byte = row
-                    .iter()
-                    .zip(rhs_error)
-                    .map(|(r, e)| r & e)
-                    .fold(byte, |acc, b| acc ^ b);
+                let byte = byte ^ backend::and_xor_fold(row, rhs_error);
 
                 *syndrome_byte |= parity_bit(byte) << selected_bit;
             }
@@ -166,64 +264,241 @@ impl PublicKey {
     }
 }
 
-// TODO: Add test.
-fn seeded_fixed_weight(seed: &[u8; ClassicMcEliece::L_BYTES]) -> [u8; ClassicMcEliece::N_BYTES] {
-    let mut seed = *seed;
-    let mut ind = [0u16; ClassicMcEliece::T];
+impl<P: ParameterSet> TryFrom<&[u8]> for PublicKey<P> {
+    type Error = Error;
 
-    loop {
-        let mut bytes = [0u8; 2 * 2 * ClassicMcEliece::T]; // TODO: ?
+    fn try_from(input: &[u8]) -> Result<Self, Error> {
+        if input.len() != Self::BYTES {
+            return Err(Error::InvalidLength);
+        }
 
-        let mut generator = Generator::new(65, &seed);
-        generator.squeeze(&mut bytes);
-        generator.squeeze(&mut seed);
+        if P::K % 8 != 0 {
+            let padding_is_zero = input
+                .chunks_exact(Self::ROW_BYTES)
+                .all(|row| row[Self::ROW_BYTES - 1] >> (P::K % 8) == 0);
 
-        let mut nums = [0u16; 2 * ClassicMcEliece::T];
-        for (num, bytes) in nums.iter_mut().zip(bytes.chunks_exact(2)) {
-            *num = u16::from_le_bytes(bytes.try_into().unwrap()) & FieldElement::MASK;
+            if !padding_is_zero {
+                return Err(Error::NonzeroPadding);
+            }
         }
 
-        // Moving and counting indices in the correct range:
+        let mut bytes = zeroed_bytes::<P>();
+        let output: &mut P::PublicKeyBytes = bytes.borrow_mut();
+        output.as_mut().copy_from_slice(input);
+        Ok(Self(bytes))
+    }
+}
 
-        let mut count = 0;
+impl<P: ParameterSet> AsRef<[u8]> for PublicKey<P> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes().as_ref()
+    }
+}
 
-        for num in nums.iter() {
-            if count >= ClassicMcEliece::T {
-                break;
-            }
-            if *num < ClassicMcEliece::N as u16 {
-                ind[count] = *num;
-                count += 1;
-            }
+#[cfg(feature = "std")]
+#[inline]
+fn zeroed_bytes<P: ParameterSet>() -> Bytes<P> {
+    P::PublicKeyBytes::zeroed_boxed()
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+fn zeroed_bytes<P: ParameterSet>() -> Bytes<P> {
+    P::PublicKeyBytes::zeroed()
+}
+
+#[cfg(feature = "kem")]
+impl<P: ParameterSet> kem::Encapsulate<Ciphertext<P>, SessionKey> for PublicKey<P> {
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn encapsulate(
+        &self,
+        rng: &mut impl rand_core::CryptoRngCore,
+    ) -> Result<(Ciphertext<P>, SessionKey), Self::Error> {
+        Ok(self.encapsulate_with_rng(rng))
+    }
+}
+
+#[cfg(feature = "std")]
+const MU: usize = 32;
+#[cfg(feature = "std")]
+const NU: usize = 64;
+
+// Finds pivots for the last MU rows among the NU columns starting at the diagonal, and swaps them
+// into place in both the matrix and the field ordering.
+#[cfg(feature = "std")]
+fn move_columns<P: ParameterSet>(
+    matrix: &mut [[u8; MAX_N_BYTES]],
+    alpha: &mut FieldOrdering<P>,
+) -> (u64, Choice) {
+    let row = P::M * P::T - MU;
+    debug_assert!(row + NU <= P::N);
+
+    let mut buf = Zeroizing::new([0u64; MU]);
+    for (buf, matrix_row) in buf.iter_mut().zip(matrix[row..].iter()) {
+        *buf = load_columns(matrix_row, row);
+    }
+
+    let mut pivots = 0;
+    let mut singular = Choice::from(0);
+    let mut ctz_list = Zeroizing::new([0; MU]);
+
+    for i in 0..MU {
+        let (pivot, others) = buf[i..].split_first_mut().unwrap();
+
+        let t = others.iter().fold(*pivot, |t, other| t | other);
+        singular |= t.ct_eq(&0);
+
+        // Setting the top bit keeps `s` in range when t is zero.
+        let s = ctz(t | (1 << 63));
+        ctz_list[i] = s;
+        pivots |= 1 << s;
+
+        for other in others.iter() {
+            let mask = ((*pivot >> s) & 1).wrapping_sub(1);
+            *pivot ^= other & mask;
         }
 
-        if count < ClassicMcEliece::T {
-            continue;
+        for other in others.iter_mut() {
+            let mask = ((*other >> s) & 1).wrapping_neg();
+            *other ^= *pivot & mask;
         }
+    }
 
-        let mut no_repetition_found = true;
+    // The pivot positions are strictly increasing, so `ctz >= j` holds unless the matrix is
+    // singular.
+    for (j, ctz) in ctz_list.iter().enumerate() {
+        for k in (j + 1)..NU {
+            alpha.conditional_swap(row + j, row + k, (k as u64).ct_eq(&(*ctz as u64)));
+        }
+    }
 
-        for i in 1..ClassicMcEliece::T {
-            for j in 0..i {
-                if ind[i] == ind[j] {
-                    no_repetition_found = false;
-                }
-            }
+    for matrix_row in matrix.iter_mut() {
+        let mut t = load_columns(matrix_row, row);
+
+        for (j, ctz) in ctz_list.iter().enumerate() {
+            let d = ((t >> j) ^ (t >> ctz)) & 1;
+            t ^= (d << ctz) | (d << j);
         }
 
-        if no_repetition_found {
+        store_columns(matrix_row, row, t);
+    }
+
+    (pivots, singular)
+}
+
+#[cfg(feature = "std")]
+fn ctz(x: u64) -> usize {
+    let mut seen = 0;
+    let mut count = 0;
+
+    for i in 0..64 {
+        let bit = (x >> i) & 1;
+        seen |= bit;
+        count += seen ^ 1;
+    }
+
+    count as usize
+}
+
+// Loads the NU columns starting at column `offset`.
+#[cfg(feature = "std")]
+fn load_columns(row: &[u8], offset: usize) -> u64 {
+    let (offset, shift) = (offset / 8, offset % 8);
+
+    let mut bytes = [0u8; 16];
+    bytes[..9].copy_from_slice(&row[offset..(offset + 9)]);
+
+    (u128::from_le_bytes(bytes) >> shift) as u64
+}
+
+#[cfg(feature = "std")]
+fn store_columns(row: &mut [u8], offset: usize, columns: u64) {
+    let (offset, shift) = (offset / 8, offset % 8);
+
+    let mut bytes = [0u8; 16];
+    bytes[..9].copy_from_slice(&row[offset..(offset + 9)]);
+
+    let mut window = u128::from_le_bytes(bytes);
+    window &= !((u64::MAX as u128) << shift);
+    window |= (columns as u128) << shift;
+
+    row[offset..(offset + 9)].copy_from_slice(&window.to_le_bytes()[..9]);
+}
+
+pub(crate) fn seeded_fixed_weight<P: ParameterSet>(
+    seed: &[u8; L_BYTES],
+) -> Zeroizing<[u8; MAX_N_BYTES]> {
+    let mut seed = Zeroizing::new(*seed);
+    let mut ind = Zeroizing::new([0u16; MAX_T]);
+    let ind = &mut ind[..P::T];
+
+    loop {
+        let mut bytes = Zeroizing::new([0u8; SIGMA_ONE_BYTES * 2 * MAX_T]);
+        let bytes = &mut bytes[..(SIGMA_ONE_BYTES * P::TAU)];
+
+        let mut generator = Generator::new(65, &seed);
+        generator.squeeze(bytes);
+        generator.squeeze(&mut *seed);
+
+        // Whether to retry may be revealed.
+        let success = fixed_weight::<P>(bytes, ind);
+        ctgrind::unpoison(&success);
+
+        if bool::from(success) {
             break;
         }
     }
 
-    let mut vals = [0u8; ClassicMcEliece::T];
+    error_vector::<P>(ind)
+}
+
+// A single attempt of FixedWeight, in constant time. Only whether it succeeded is revealed; the
+// indices of a failed attempt are meaningless.
+pub(crate) fn fixed_weight<P: ParameterSet>(bytes: &[u8], ind: &mut [u16]) -> Choice {
+    debug_assert_eq!(bytes.len(), SIGMA_ONE_BYTES * P::TAU);
+    debug_assert_eq!(ind.len(), P::T);
+
+    // Moving and counting indices in the correct range:
+
+    ind.fill(0);
+    let mut count = 0;
+
+    for (i, bytes) in bytes.chunks_exact(2).enumerate() {
+        let num = u16::from_le_bytes(bytes.try_into().unwrap()) & FieldElement::<P>::MASK;
+        let in_range = (num as u32).wrapping_sub(P::N as u32) >> 31;
+
+        // count <= i, so later indices are never written.
+        for (j, ind) in ind.iter_mut().enumerate().take(i + 1) {
+            let mask = (eq_mask(count, j as u32) & (in_range as u8).wrapping_neg()) as i8 as u16;
+            *ind ^= (*ind ^ num) & mask;
+        }
+
+        count += in_range;
+    }
+
+    let mut failure = (count.wrapping_sub(P::T as u32) >> 31) as u8;
+
+    for i in 1..P::T {
+        for j in 0..i {
+            failure |= eq_mask(ind[i] as u32, ind[j] as u32) & 1;
+        }
+    }
+
+    !Choice::from(failure)
+}
+
+pub(crate) fn error_vector<P: ParameterSet>(ind: &[u16]) -> Zeroizing<[u8; MAX_N_BYTES]> {
+    let mut vals = Zeroizing::new([0u8; MAX_T]);
     for (val, ind) in vals.iter_mut().zip(ind.iter()) {
         *val = 1 << (*ind & 7) as u8;
     }
 
-    let mut error = [0u8; ClassicMcEliece::N_BYTES];
+    let mut error = Zeroizing::new([0u8; MAX_N_BYTES]);
 
-    for (i, e) in error.iter_mut().enumerate() {
+    for (i, e) in error.iter_mut().take(P::N_BYTES).enumerate() {
         for (ind, val) in ind.iter().zip(vals.iter()) {
             *e |= val & eq_mask(i as u32, *ind as u32 >> 3);
         }
@@ -232,6 +507,18 @@ fn seeded_fixed_weight(seed: &[u8; ClassicMcEliece::L_BYTES]) -> [u8; ClassicMcE
     error
 }
 
+// Copies the bits of `input` starting at bit `offset`, since neither the rows of the public key
+// nor the syndrome are necessarily byte-aligned.
+fn copy_from_bit(output: &mut [u8], input: &[u8], offset: usize) {
+    let (offset, shift) = (offset / 8, offset % 8);
+
+    for (i, output) in output.iter_mut().enumerate() {
+        let lo = input[offset + i] as u16;
+        let hi = input.get(offset + i + 1).copied().unwrap_or(0) as u16;
+        *output = (((hi << 8) | lo) >> shift) as u8;
+    }
+}
+
 fn eq_mask(x: u32, y: u32) -> u8 {
     let mut mask = x ^ y;
     mask = mask.wrapping_sub(1);
@@ -243,3 +530,291 @@ fn eq_mask(x: u32, y: u32) -> u8 {
 fn parity_bit(byte: u8) -> u8 {
     [4, 2, 1].iter().fold(byte, |acc, s| acc ^ (acc >> s)) & 0b1
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::convert::TryFrom;
+
+    use rand::{RngCore, SeedableRng};
+
+    use crate::classic_mceliece::ClassicMcEliece;
+    use crate::ctr_drbg::{kat_seeds, CtrDrbg};
+    use crate::error::Error;
+    use crate::field_element::FieldElement;
+    use crate::parameter_set::*;
+    use crate::public_key::{error_vector, fixed_weight, seeded_fixed_weight, PublicKey};
+
+    #[test]
+    fn encapsulate_with_rng() {
+        type P = McEliece460896;
+
+        let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let (ciphertext, session_key) = public_key.encapsulate_with_rng(&mut rng);
+
+        let mut seed = [0u8; L_BYTES];
+        rand::rngs::StdRng::seed_from_u64(0).fill_bytes(&mut seed);
+        let error = seeded_fixed_weight::<P>(&seed);
+        let (expected_ciphertext, expected_session_key) =
+            public_key.encapsulate_deterministic(&error[..P::N_BYTES]);
+
+        assert_eq!(ciphertext, expected_ciphertext);
+        assert_eq!(session_key, expected_session_key);
+        assert_eq!(secret_key.decapsulate(&ciphertext), session_key);
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn encapsulate_from_seed() {
+        type P = McEliece460896;
+
+        let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+
+        let mut seed = [0u8; L_BYTES];
+        rand::rngs::StdRng::seed_from_u64(0).fill_bytes(&mut seed);
+        let (ciphertext, session_key) = public_key.encapsulate_from_seed(&seed);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let (expected_ciphertext, expected_session_key) = public_key.encapsulate_with_rng(&mut rng);
+
+        assert_eq!(ciphertext, expected_ciphertext);
+        assert_eq!(session_key, expected_session_key);
+        assert_eq!(secret_key.decapsulate(&ciphertext), session_key);
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn encapsulate_with_error_vector() {
+        type P = McEliece6960119;
+
+        let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+
+        let seed = [1u8; L_BYTES];
+        let error = seeded_fixed_weight::<P>(&seed);
+        let error = &error[..P::N_BYTES];
+
+        let (ciphertext, session_key) = public_key.encapsulate_with_error_vector(error).unwrap();
+        let (expected_ciphertext, expected_session_key) = public_key.encapsulate_from_seed(&seed);
+        assert_eq!(ciphertext, expected_ciphertext);
+        assert_eq!(session_key, expected_session_key);
+        assert_eq!(secret_key.decapsulate(&ciphertext), session_key);
+
+        assert_eq!(
+            public_key.encapsulate_with_error_vector(&error[1..]).err(),
+            Some(Error::InvalidLength)
+        );
+
+        let i = (0..P::N)
+            .find(|i| (error[i / 8] >> (i % 8)) & 1 == 0)
+            .unwrap();
+        let mut heavier = error.to_vec();
+        heavier[i / 8] |= 1 << (i % 8);
+        assert_eq!(
+            public_key.encapsulate_with_error_vector(&heavier).err(),
+            Some(Error::InvalidWeight)
+        );
+
+        let i = (0..P::N)
+            .find(|i| (error[i / 8] >> (i % 8)) & 1 == 1)
+            .unwrap();
+        let mut lighter = error.to_vec();
+        lighter[i / 8] &= !(1 << (i % 8));
+        assert_eq!(
+            public_key.encapsulate_with_error_vector(&lighter).err(),
+            Some(Error::InvalidWeight)
+        );
+    }
+
+    #[test]
+    fn nonzero_padding_is_rejected() {
+        type P = McEliece6960119;
+
+        let mut bytes = vec![0u8; PublicKey::<P>::BYTES];
+        assert!(PublicKey::<P>::try_from(&bytes[..]).is_ok());
+
+        assert_eq!(
+            PublicKey::<P>::try_from(&bytes[1..]).err(),
+            Some(Error::InvalidLength)
+        );
+
+        bytes[P::K_BYTES - 1] = 1 << (P::K % 8);
+        assert_eq!(
+            PublicKey::<P>::try_from(&bytes[..]).err(),
+            Some(Error::NonzeroPadding)
+        );
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Kat {
+        count: usize,
+        seed: String,
+        e: String,
+    }
+
+    // The KATs whose error vectors are patched in, all for mceliece348864.
+    fn kats() -> Vec<Kat> {
+        serde_json::from_str(include_str!("../tests/kats-patch.json")).unwrap()
+    }
+
+    #[test]
+    fn fixed_weight_kats() {
+        type P = McEliece348864;
+
+        let kats = kats();
+
+        let count_seeds = kat_seeds(kats.iter().map(|kat| kat.count).max().unwrap() + 1);
+
+        for kat in kats {
+            let mut drbg = CtrDrbg::new(&count_seeds[kat.count]);
+
+            let mut seed = [0u8; L_BYTES];
+            drbg.fill(&mut seed);
+            assert_eq!(hex::encode(seed), kat.seed);
+
+            let mut bytes = vec![0u8; SIGMA_ONE_BYTES * P::TAU];
+            let mut ind = [0u16; MAX_T];
+
+            loop {
+                drbg.fill(&mut bytes);
+                if bool::from(fixed_weight::<P>(&bytes, &mut ind[..P::T])) {
+                    break;
+                }
+            }
+
+            let error = error_vector::<P>(&ind[..P::T]);
+            assert_eq!(hex::encode(&error[..P::N_BYTES]), kat.e);
+        }
+    }
+
+    // Synthetic attempts, not taken from the KATs, that draw the indices of the patched error
+    // vectors, each one after a number out of range, so that single indices are easy to change.
+    fn synthetic_attempts() -> Vec<(Vec<u8>, Vec<u8>)> {
+        type P = McEliece348864;
+
+        kats()
+            .iter()
+            .map(|kat| {
+                let e = hex::decode(&kat.e).unwrap();
+
+                let mut bytes = Vec::new();
+                for (i, ind) in (0..P::N)
+                    .filter(|i| (e[i / 8] >> (i % 8)) & 1 == 1)
+                    .enumerate()
+                {
+                    // Also sets bits above M, which are masked off.
+                    bytes.extend_from_slice(&((P::N + i) as u16 | 0xf000).to_le_bytes());
+                    bytes.extend_from_slice(&(ind as u16).to_le_bytes());
+                }
+
+                (bytes, e)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fixed_weight_synthetic() {
+        type P = McEliece348864;
+
+        for (bytes, expected_error) in synthetic_attempts() {
+            assert_eq!(bytes.len(), SIGMA_ONE_BYTES * P::TAU);
+
+            let mut ind = [0u16; MAX_T];
+            assert!(bool::from(fixed_weight::<P>(&bytes, &mut ind[..P::T])));
+
+            let error = error_vector::<P>(&ind[..P::T]);
+            assert_eq!(&error[..P::N_BYTES], &expected_error[..]);
+        }
+    }
+
+    #[test]
+    fn fixed_weight_rejects_repetition() {
+        type P = McEliece348864;
+
+        for (mut bytes, _) in synthetic_attempts() {
+            let (lhs, rhs) = bytes.split_at_mut(4 * (P::T - 1));
+            rhs[2..].copy_from_slice(&lhs[2..4]);
+
+            assert!(!bool::from(fixed_weight::<P>(
+                &bytes,
+                &mut [0; MAX_T][..P::T]
+            )));
+        }
+    }
+
+    #[test]
+    fn fixed_weight_rejects_too_few_indices() {
+        type P = McEliece348864;
+
+        for (mut bytes, _) in synthetic_attempts() {
+            bytes[(4 * P::T - 2)..].copy_from_slice(&(P::N as u16).to_le_bytes());
+
+            assert!(!bool::from(fixed_weight::<P>(
+                &bytes,
+                &mut [0; MAX_T][..P::T]
+            )));
+        }
+    }
+
+    // A single attempt of FixedWeight as written in the specification.
+    fn reference_fixed_weight<P: ParameterSet>(bytes: &[u8]) -> Option<Vec<u8>> {
+        let ind: Vec<usize> = bytes
+            .chunks_exact(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) & FieldElement::<P>::MASK)
+            .map(usize::from)
+            .filter(|num| *num < P::N)
+            .take(P::T)
+            .collect();
+
+        if ind.len() < P::T || (1..P::T).any(|i| ind[..i].contains(&ind[i])) {
+            return None;
+        }
+
+        let mut error = vec![0u8; P::N_BYTES];
+        for ind in ind {
+            error[ind / 8] |= 1 << (ind % 8);
+        }
+        Some(error)
+    }
+
+    fn fixed_weight_matches_reference<P: ParameterSet>() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut bytes = vec![0u8; SIGMA_ONE_BYTES * P::TAU];
+
+        let mut ind = [0u16; MAX_T];
+        let mut successes = 0;
+
+        for _ in 0..1000 {
+            rng.fill_bytes(&mut bytes);
+
+            let success = fixed_weight::<P>(&bytes, &mut ind[..P::T]);
+
+            match reference_fixed_weight::<P>(&bytes) {
+                Some(expected_error) => {
+                    assert!(bool::from(success));
+                    let error = error_vector::<P>(&ind[..P::T]);
+                    assert_eq!(&error[..P::N_BYTES], &expected_error[..]);
+                    successes += 1;
+                }
+                None => assert!(!bool::from(success)),
+            }
+        }
+
+        assert!(successes > 0);
+    }
+
+    #[test]
+    fn fixed_weight_matches_reference_mceliece348864() {
+        fixed_weight_matches_reference::<McEliece348864>();
+    }
+
+    #[test]
+    fn fixed_weight_matches_reference_mceliece6960119() {
+        fixed_weight_matches_reference::<McEliece6960119>();
+    }
+
+    #[test]
+    fn fixed_weight_matches_reference_mceliece8192128() {
+        fixed_weight_matches_reference::<McEliece8192128>();
+    }
+}
@@ -1,126 +1,259 @@
-use std::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater};
+use zeroize::{Zeroize, Zeroizing};
 
+use crate::buffer::NBuffer;
+use crate::ciphertext::Ciphertext;
+#[cfg(feature = "std")]
 use crate::classic_mceliece::ClassicMcEliece;
 use crate::control_bits::ControlBits;
+use crate::ctgrind;
+use crate::error::Error;
+use crate::fft::fft_tr;
 use crate::field_element::FieldElement;
+#[cfg(feature = "std")]
 use crate::field_ordering::FieldOrdering;
+use crate::field_vector::invert;
 use crate::monic_polynomial::MonicPolynomial;
-
-pub struct SecretKey {
-    #[allow(dead_code)] // TODO: Exporting fn.
-    pub(crate) seed: [u8; Self::SEED_BYTES],
-    pub(crate) g: MonicPolynomial,
-    pub(crate) control_bits: ControlBits,
-    pub(crate) s: [u8; Self::S_BYTES],
+use crate::parameter_set::{ByteArray, ParameterSet, L_BYTES, MAX_N_BYTES, MAX_T};
+use crate::session_key::SessionKey;
+
+/// A secret key. It is zeroized when dropped, and can be wiped earlier through `Zeroize`.
+pub struct SecretKey<P: ParameterSet> {
+    pub(crate) seed: [u8; L_BYTES],
+    pub(crate) pivots: u64,
+    pub(crate) g: MonicPolynomial<P>,
+    pub(crate) control_bits: ControlBits<P>,
+    pub(crate) s: [u8; MAX_N_BYTES],
 }
 
-impl SecretKey {
-    const SEED_BYTES: usize = ClassicMcEliece::L_BYTES;
+impl<P: ParameterSet> SecretKey<P> {
+    const SEED_BYTES: usize = L_BYTES;
 
-    pub(crate) const S_BYTES: usize = ClassicMcEliece::N_BYTES;
+    pub(crate) const S_BYTES: usize = P::N_BYTES;
 
-    pub const BYTES: usize =
-        Self::SEED_BYTES + 8 + MonicPolynomial::BYTES + ControlBits::BYTES + Self::S_BYTES;
+    pub const BYTES: usize = Self::SEED_BYTES
+        + 8
+        + MonicPolynomial::<P>::BYTES
+        + ControlBits::<P>::BYTES
+        + Self::S_BYTES;
 
+    #[cfg(feature = "std")]
     #[inline]
     pub(crate) fn new(
-        seed: &[u8; Self::SEED_BYTES],
-        g: &MonicPolynomial,
-        alpha: &FieldOrdering,
-        s: &[u8; Self::S_BYTES],
+        seed: &[u8; L_BYTES],
+        pivots: u64,
+        g: &MonicPolynomial<P>,
+        alpha: &FieldOrdering<P>,
+        s: &[u8],
     ) -> Self {
-        SecretKey {
+        debug_assert_eq!(s.len(), Self::S_BYTES);
+
+        let mut secret_key = SecretKey {
             seed: *seed,
+            pivots,
             g: *g,
             control_bits: alpha.into(),
-            s: *s,
-        }
+            s: [0; MAX_N_BYTES],
+        };
+        secret_key.s[..Self::S_BYTES].copy_from_slice(s);
+        secret_key
     }
 
-    // TODO: Return Option/Result.
-    pub fn from_bytes(input: &[u8; Self::BYTES]) -> Self {
-        let mut input = &input[..];
+    /// Regenerates the full key from the seed returned by `to_seed`. This reruns key generation,
+    /// which makes it as expensive as generating a new key.
+    #[cfg(feature = "std")]
+    pub fn from_seed(seed: &[u8; L_BYTES]) -> Self {
+        let (secret_key, _) = ClassicMcEliece::<P>::generate_keypair_from_seed(seed);
+        secret_key
+    }
+
+    /// Returns the 32-byte seed the key was generated from.
+    #[inline]
+    pub fn to_seed(&self) -> [u8; L_BYTES] {
+        self.seed
+    }
+
+    /// Parses a secret key, checking the pivots and the range of the Goppa polynomial
+    /// coefficients. The control bits are not checked: every bit string of the right length is a
+    /// Benes network for some permutation. Neither this nor `from_bytes_checked` checks that the
+    /// Goppa polynomial is irreducible.
+    pub fn from_bytes(input: &P::SecretKeyBytes) -> Result<Self, Error> {
+        let mut input = input.as_ref();
 
         let seed = input[..Self::SEED_BYTES].try_into().unwrap();
         input = &input[Self::SEED_BYTES..];
 
-        assert_eq!(
-            input[0..8],
-            [0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00]
-        );
+        let pivots = u64::from_le_bytes(input[..8].try_into().unwrap());
+        let pivots_are_valid = if P::SEMI_SYSTEMATIC {
+            pivots.count_ones() == 32
+        } else {
+            pivots == 0xffff_ffff
+        };
+        if !pivots_are_valid {
+            return Err(Error::InvalidPivots);
+        }
         input = &input[8..];
 
-        let g = MonicPolynomial::from_bytes(&input[..MonicPolynomial::BYTES].try_into().unwrap())
-            .unwrap();
-        input = &input[MonicPolynomial::BYTES..];
+        let g = MonicPolynomial::from_bytes(&input[..MonicPolynomial::<P>::BYTES])
+            .ok_or(Error::InvalidGoppaPolynomial)?;
+        input = &input[MonicPolynomial::<P>::BYTES..];
 
-        let control_bits = ControlBits::from_bytes(input[..ControlBits::BYTES].try_into().unwrap());
-        input = &input[ControlBits::BYTES..];
+        let control_bits = ControlBits::from_bytes(&input[..ControlBits::<P>::BYTES]);
+        input = &input[ControlBits::<P>::BYTES..];
 
-        let s = input[..Self::S_BYTES].try_into().unwrap();
+        let mut s = [0; MAX_N_BYTES];
+        s[..Self::S_BYTES].copy_from_slice(&input[..Self::S_BYTES]);
         input = &input[Self::S_BYTES..];
 
         debug_assert!(input.is_empty());
 
-        SecretKey {
+        Ok(SecretKey {
             seed,
+            pivots,
             g,
             control_bits,
             s,
+        })
+    }
+
+    /// Like `from_bytes`, but also rejects control bits that are not the canonical ones key
+    /// generation computes for the permutation they encode. This recomputes them, which costs
+    /// about as much as the control bit step of key generation.
+    pub fn from_bytes_checked(input: &P::SecretKeyBytes) -> Result<Self, Error> {
+        let secret_key = Self::from_bytes(input)?;
+
+        if !secret_key.control_bits.is_canonical() {
+            return Err(Error::InvalidControlBits);
         }
+
+        Ok(secret_key)
+    }
+
+    pub fn to_bytes(&self) -> P::SecretKeyBytes {
+        let mut bytes = P::SecretKeyBytes::zeroed();
+        let mut output = bytes.as_mut();
+
+        output[..Self::SEED_BYTES].copy_from_slice(&self.seed);
+        output = &mut output[Self::SEED_BYTES..];
+
+        output[..8].copy_from_slice(&self.pivots.to_le_bytes());
+        output = &mut output[8..];
+
+        self.g
+            .write_bytes(&mut output[..MonicPolynomial::<P>::BYTES]);
+        output = &mut output[MonicPolynomial::<P>::BYTES..];
+
+        output[..ControlBits::<P>::BYTES].copy_from_slice(self.control_bits.as_bytes());
+        output = &mut output[ControlBits::<P>::BYTES..];
+
+        output[..Self::S_BYTES].copy_from_slice(&self.s[..Self::S_BYTES]);
+        output = &mut output[Self::S_BYTES..];
+
+        debug_assert!(output.is_empty());
+
+        bytes
     }
 
-    pub fn decapsulate(
-        &self,
-        ciphertext: &[u8; ClassicMcEliece::CIPHERTEXT_BYTES],
-    ) -> [u8; ClassicMcEliece::SESSION_KEY_BYTES] {
+    /// Decapsulates `ciphertext`, returning the session key it carries.
+    ///
+    /// Invalid ciphertexts are implicitly rejected: decapsulation never fails, but returns a
+    /// pseudorandom session key derived from the secret key and the ciphertext instead.
+    ///
+    /// Ciphertexts whose unused padding bits at the end of `c0` are not zero (only
+    /// `mceliece6960119` and `mceliece6960119f` have any) are implicitly rejected as well. This
+    /// differs from the reference implementation, which reports them as invalid and returns an
+    /// all-zero session key, so the session keys of the two differ for such ciphertexts. Honest
+    /// encapsulation never produces them.
+    pub fn decapsulate(&self, ciphertext: &Ciphertext<P>) -> SessionKey {
         use crate::hash::*;
 
-        let c0 = &ciphertext[..ClassicMcEliece::C0_BYTES];
-        let c1 = &ciphertext[ClassicMcEliece::C0_BYTES..];
+        self.poison();
+
+        let c0 = &ciphertext.as_ref()[..P::C0_BYTES];
+        let c1 = &ciphertext.as_ref()[P::C0_BYTES..];
+
+        let s = &self.s[..Self::S_BYTES];
 
-        let mut error = [0u8; ClassicMcEliece::N_BYTES];
-        let mut success = self.decode(c0.try_into().unwrap(), &mut error);
+        let mut error = Zeroizing::new([0u8; MAX_N_BYTES]);
+        let error = &mut error[..P::N_BYTES];
+        let mut success = self.decode(c0, error);
 
-        for (error, s) in error.iter_mut().zip(self.s.iter()) {
+        // Ciphertexts with nonzero padding bits are rejected like any other invalid ciphertext:
+        if (P::M * P::T) % 8 != 0 {
+            success &= (c0[P::C0_BYTES - 1] >> ((P::M * P::T) % 8)).ct_eq(&0);
+        }
+
+        for (error, s) in error.iter_mut().zip(s.iter()) {
             error.conditional_assign(s, !success);
         }
 
-        let mut c1_prime = [0u8; ClassicMcEliece::C1_BYTES];
-        hash_2(&error, &mut c1_prime);
+        let mut c1_prime = Zeroizing::new([0u8; L_BYTES]);
+        hash_2(error, &mut c1_prime);
 
-        success &= c1_prime.ct_eq(c1);
+        success &= c1_prime[..].ct_eq(c1);
 
-        for (error, s) in error.iter_mut().zip(self.s.iter()) {
+        for (error, s) in error.iter_mut().zip(s.iter()) {
             error.conditional_assign(s, !success);
         }
 
-        let mut session_key = [0u8; ClassicMcEliece::SESSION_KEY_BYTES];
+        let mut session_key = SessionKey::zeroed();
+
+        hash_x(
+            success.unwrap_u8(),
+            error,
+            ciphertext.as_ref(),
+            &mut session_key.0,
+        );
 
-        hash_x(success.unwrap_u8(), &error, ciphertext, &mut session_key);
+        self.unpoison();
+        ctgrind::unpoison(&session_key);
 
         session_key
     }
 
-    fn decode(
-        &self,
-        c0: &[u8; ClassicMcEliece::C0_BYTES],
-        error: &mut [u8; ClassicMcEliece::N_BYTES],
-    ) -> Choice {
-        let mut v = [0u8; ClassicMcEliece::N_BYTES]; // TODO: Name?
-        v[..ClassicMcEliece::C0_BYTES].copy_from_slice(c0);
+    // Marks the contents of the key as secret for ctgrind, or as safe to reveal again.
+    pub(crate) fn poison(&self) {
+        ctgrind::poison(&self.seed);
+        ctgrind::poison(&self.pivots);
+        ctgrind::poison(&self.g);
+        ctgrind::poison(self.control_bits.as_bytes());
+        ctgrind::poison(&self.s);
+    }
+
+    pub(crate) fn unpoison(&self) {
+        ctgrind::unpoison(&self.seed);
+        ctgrind::unpoison(&self.pivots);
+        ctgrind::unpoison(&self.g);
+        ctgrind::unpoison(self.control_bits.as_bytes());
+        ctgrind::unpoison(&self.s);
+    }
+
+    pub(crate) fn decode(&self, c0: &[u8], error: &mut [u8]) -> Choice {
+        debug_assert_eq!(c0.len(), P::C0_BYTES);
+        debug_assert_eq!(error.len(), P::N_BYTES);
+
+        let mut v = [0u8; MAX_N_BYTES]; // TODO: Name?
+        v[..P::C0_BYTES].copy_from_slice(c0);
+        if (P::M * P::T) % 8 != 0 {
+            v[P::C0_BYTES - 1] &= (1 << ((P::M * P::T) % 8)) - 1;
+        }
 
         // Compute syndrome and images:
 
-        let support = self.control_bits.generate_support();
+        let mut scale = self.control_bits.to_support_order(&self.g.root());
+        for scale in scale.iter_mut() {
+            *scale = scale.square();
+        }
+        invert(&mut scale);
 
-        let syndrome = synd(&self.g, &support, &v);
+        let syndrome = synd(&self.control_bits, &scale, &v[..P::N_BYTES]);
+        let syndrome = &syndrome[..(2 * P::T)];
 
-        let locator = berlenkamp_massey(&syndrome);
+        let locator = Zeroizing::new(berlenkamp_massey(syndrome));
 
-        let images = locator.root(&support);
+        let images = self.control_bits.to_support_order(&locator.root());
 
         // Compute error and new syndrome:
 
@@ -135,44 +268,94 @@ impl SecretKey {
             }
         }
 
-        let other_syndrome = synd(&self.g, &support, error);
+        let other_syndrome = synd(&self.control_bits, &scale, error);
 
-        weight.ct_eq(&ClassicMcEliece::T) & syndrome.ct_eq(&other_syndrome)
+        weight.ct_eq(&P::T) & syndrome.ct_eq(&other_syndrome[..(2 * P::T)])
     }
 }
 
-fn synd(
-    f: &MonicPolynomial,
-    support: &[FieldElement; ClassicMcEliece::N],
-    received_word: &[u8; ClassicMcEliece::N_BYTES],
-) -> [FieldElement; 2 * ClassicMcEliece::T] {
-    let mut syndrome = [FieldElement::ZERO; 2 * ClassicMcEliece::T];
+impl<P: ParameterSet> TryFrom<&[u8]> for SecretKey<P> {
+    type Error = Error;
 
-    for i in 0..ClassicMcEliece::N {
-        let c = (received_word[i / 8] as u16 >> (i % 8)) & 0b1;
+    fn try_from(input: &[u8]) -> Result<Self, Error> {
+        if input.len() != Self::BYTES {
+            return Err(Error::InvalidLength);
+        }
 
-        let e = f.evaluate_at(support[i]);
-        let mut e_inv = e.square().inverse();
+        let mut bytes = P::SecretKeyBytes::zeroed();
+        bytes.as_mut().copy_from_slice(input);
+        Self::from_bytes(&bytes)
+    }
+}
 
-        for syndrome_limb in syndrome.iter_mut().take(2 * ClassicMcEliece::T) {
-            *syndrome_limb += e_inv * FieldElement::from(c);
-            e_inv *= support[i];
-        }
+impl<P: ParameterSet> Zeroize for SecretKey<P> {
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.pivots.zeroize();
+        self.g.zeroize();
+        self.control_bits.zeroize();
+        self.s.zeroize();
+    }
+}
+
+impl<P: ParameterSet> Drop for SecretKey<P> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "kem")]
+impl<P: ParameterSet> kem::Decapsulate<Ciphertext<P>, SessionKey> for SecretKey<P> {
+    type Error = core::convert::Infallible;
+
+    // Invalid ciphertexts are implicitly rejected, so decapsulation never fails.
+    #[inline]
+    fn decapsulate(&self, ciphertext: &Ciphertext<P>) -> Result<SessionKey, Self::Error> {
+        Ok(SecretKey::decapsulate(self, ciphertext))
+    }
+}
+
+// Computes the syndrome, the sums of c_i * α_i^j / g(α_i)^2 over the support for j < 2T, as the
+// transpose of evaluating at the support; `scale` holds the 1 / g(α_i)^2.
+fn synd<P: ParameterSet>(
+    control_bits: &ControlBits<P>,
+    scale: &[FieldElement<P>],
+    received_word: &[u8],
+) -> Zeroizing<[FieldElement<P>; 2 * MAX_T]> {
+    debug_assert_eq!(scale.len(), P::N);
+    debug_assert_eq!(received_word.len(), P::N_BYTES);
+
+    let mut values = NBuffer::<P, _>::new(FieldElement::ZERO, P::N);
+
+    for (i, (value, scale)) in values.iter_mut().zip(scale.iter()).enumerate() {
+        let c = (received_word[i / 8] >> (i % 8)) & 0b1;
+        *value = FieldElement::conditional_select(&FieldElement::ZERO, scale, c.into());
     }
 
+    let mut values = control_bits.to_fft_order(&values);
+
+    let mut syndrome = Zeroizing::new([FieldElement::ZERO; 2 * MAX_T]);
+    fft_tr(&mut values, &mut syndrome[..(2 * P::T)]);
+
     syndrome
 }
 
-fn berlenkamp_massey(syndrome: &[FieldElement; 2 * ClassicMcEliece::T]) -> MonicPolynomial {
+pub(crate) fn berlenkamp_massey<P: ParameterSet>(
+    syndrome: &[FieldElement<P>],
+) -> MonicPolynomial<P> {
+    debug_assert_eq!(syndrome.len(), 2 * P::T);
+
     let mut last_discrepancy = FieldElement::ONE;
     let mut length = 0;
 
-    let mut bee = [FieldElement::ZERO; ClassicMcEliece::T + 1];
-    let mut connection = [FieldElement::ZERO; ClassicMcEliece::T + 1];
+    let mut bee = Zeroizing::new([FieldElement::ZERO; MAX_T + 1]);
+    let mut connection = Zeroizing::new([FieldElement::ZERO; MAX_T + 1]);
+    let bee = &mut bee[..=P::T];
+    let connection = &mut connection[..=P::T];
     bee[1] = FieldElement::ONE;
     connection[0] = FieldElement::ONE;
 
-    for n in 0..(2 * ClassicMcEliece::T) {
+    for n in 0..(2 * P::T) {
         let discrepancy = connection
             .iter()
             .zip(syndrome.iter().take(n + 1).rev())
@@ -181,7 +364,8 @@ fn berlenkamp_massey(syndrome: &[FieldElement; 2 * ClassicMcEliece::T]) -> Monic
         let discrepancy_is_zero = discrepancy.ct_eq(&FieldElement::ZERO);
         let do_step_5 = !discrepancy_is_zero & !(2 * length).ct_gt(&(n as u16));
 
-        let connection_copy = connection;
+        let mut connection_copy = Zeroizing::new([FieldElement::ZERO; MAX_T + 1]);
+        connection_copy[..=P::T].copy_from_slice(connection);
 
         let adjustment_factor = discrepancy / last_discrepancy;
 
@@ -206,5 +390,271 @@ fn berlenkamp_massey(syndrome: &[FieldElement; 2 * ClassicMcEliece::T]) -> Monic
         bee[0] = FieldElement::ZERO;
     }
 
-    MonicPolynomial::reversing_explicitly_monic(&connection)
+    MonicPolynomial::reversing_explicitly_monic(connection)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::convert::TryFrom;
+
+    use std::mem::MaybeUninit;
+    use std::ptr::{self, addr_of};
+
+    use proptest::prelude::*;
+    use rand::Rng;
+    use zeroize::Zeroize;
+
+    use crate::classic_mceliece::ClassicMcEliece;
+    use crate::control_bits::ControlBits;
+    use crate::error::Error;
+    use crate::field_element::FieldElement;
+    use crate::field_ordering::FieldOrdering;
+    use crate::monic_polynomial::MonicPolynomial;
+    use crate::parameter_set::*;
+    use crate::secret_key::{berlenkamp_massey, SecretKey};
+
+    fn random_bytes<P: ParameterSet>() -> P::SecretKeyBytes {
+        let mut bytes = P::SecretKeyBytes::zeroed();
+        rand::thread_rng().fill(bytes.as_mut());
+
+        let pivots: u64 = if P::SEMI_SYSTEMATIC {
+            0x0000_0002_efff_ffff
+        } else {
+            0xffff_ffff
+        };
+        bytes.as_mut()[L_BYTES..][..8].copy_from_slice(&pivots.to_le_bytes());
+
+        let g = &mut bytes.as_mut()[(L_BYTES + 8)..][..MonicPolynomial::<P>::BYTES];
+        for limb in g.chunks_exact_mut(2) {
+            limb[1] &= (((1u16 << P::M) - 1) >> 8) as u8;
+        }
+
+        let alpha = loop {
+            let mut seed = vec![0u8; FieldOrdering::<P>::BYTES];
+            rand::thread_rng().fill(&mut seed[..]);
+            if let Some(alpha) = FieldOrdering::<P>::new(&seed) {
+                break alpha;
+            }
+        };
+        bytes.as_mut()[control_bits_offset::<P>()..][..ControlBits::<P>::BYTES]
+            .copy_from_slice(ControlBits::from(&alpha).as_bytes());
+
+        bytes
+    }
+
+    fn control_bits_offset<P: ParameterSet>() -> usize {
+        L_BYTES + 8 + MonicPolynomial::<P>::BYTES
+    }
+
+    fn bytes_round_trip<P: ParameterSet>() {
+        let bytes = random_bytes::<P>();
+
+        let secret_key = SecretKey::<P>::from_bytes(&bytes).unwrap();
+        assert_eq!(secret_key.to_bytes(), bytes);
+
+        let secret_key = SecretKey::<P>::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(secret_key.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn bytes_round_trip_mceliece348864() {
+        bytes_round_trip::<McEliece348864>();
+    }
+
+    #[test]
+    fn bytes_round_trip_mceliece6960119f() {
+        bytes_round_trip::<McEliece6960119f>();
+    }
+
+    #[test]
+    fn invalid_bytes_are_rejected() {
+        type P = McEliece348864;
+
+        let bytes = random_bytes::<P>();
+
+        assert_eq!(
+            SecretKey::<P>::try_from(&bytes[1..]).err(),
+            Some(Error::InvalidLength)
+        );
+
+        let mut invalid_pivots = bytes;
+        invalid_pivots[L_BYTES + 4] = 0x01;
+        assert_eq!(
+            SecretKey::<P>::from_bytes(&invalid_pivots).err(),
+            Some(Error::InvalidPivots)
+        );
+
+        let mut invalid_g = bytes;
+        invalid_g[L_BYTES + 8 + 1] |= 0x10;
+        assert_eq!(
+            SecretKey::<P>::from_bytes(&invalid_g).err(),
+            Some(Error::InvalidGoppaPolynomial)
+        );
+
+        let mut invalid_control_bits = bytes;
+        invalid_control_bits[control_bits_offset::<P>()] ^= 0x01;
+        assert_eq!(
+            SecretKey::<P>::from_bytes_checked(&invalid_control_bits).err(),
+            Some(Error::InvalidControlBits)
+        );
+        assert_eq!(
+            SecretKey::<P>::from_bytes(&invalid_control_bits)
+                .unwrap()
+                .to_bytes(),
+            invalid_control_bits
+        );
+
+        assert!(SecretKey::<P>::from_bytes_checked(&bytes).is_ok());
+    }
+
+    // `Drop` calls `zeroize`, so checking a live, zeroized key covers what is wiped on drop.
+    #[test]
+    fn zeroized() {
+        type P = McEliece348864f;
+
+        let bytes = random_bytes::<P>();
+
+        let mut secret_key = SecretKey::<P>::from_bytes(&bytes).unwrap();
+        assert!(secret_key
+            .control_bits
+            .as_bytes()
+            .iter()
+            .any(|byte| *byte != 0));
+
+        secret_key.zeroize();
+
+        assert_eq!(secret_key.seed, [0; L_BYTES]);
+        assert_eq!(secret_key.pivots, 0);
+        assert!(secret_key.g.0.iter().all(|limb| *limb == 0));
+        assert_eq!(
+            secret_key.control_bits.as_bytes().len(),
+            ControlBits::<P>::BYTES
+        );
+        assert!(secret_key
+            .control_bits
+            .as_bytes()
+            .iter()
+            .all(|byte| *byte == 0));
+        assert!(secret_key.s.iter().all(|byte| *byte == 0));
+    }
+
+    // Runs the actual `Drop` on a key that lives in a `MaybeUninit`, whose memory stays readable
+    // afterwards. The control bits are on the heap and freed; `tests/zeroize.rs` covers those.
+    #[test]
+    fn zeroized_on_drop() {
+        type P = McEliece348864f;
+
+        let bytes = random_bytes::<P>();
+
+        let mut secret_key = MaybeUninit::new(SecretKey::<P>::from_bytes(&bytes).unwrap());
+        let secret_key = secret_key.as_mut_ptr();
+
+        unsafe {
+            assert_ne!(addr_of!((*secret_key).seed).read(), [0; L_BYTES]);
+            assert_ne!(addr_of!((*secret_key).pivots).read(), 0);
+
+            ptr::drop_in_place(secret_key);
+
+            assert_eq!(addr_of!((*secret_key).seed).read(), [0; L_BYTES]);
+            assert_eq!(addr_of!((*secret_key).pivots).read(), 0);
+            assert!(addr_of!((*secret_key).g)
+                .read()
+                .0
+                .iter()
+                .all(|limb| *limb == 0));
+            assert!(addr_of!((*secret_key).s)
+                .read()
+                .iter()
+                .all(|byte| *byte == 0));
+        }
+    }
+
+    #[test]
+    fn seed_round_trip() {
+        type P = McEliece348864f;
+
+        let (secret_key, _) = ClassicMcEliece::<P>::generate_keypair();
+
+        let regenerated = SecretKey::<P>::from_seed(&secret_key.to_seed());
+
+        assert_eq!(regenerated.to_seed(), secret_key.to_seed());
+        assert_eq!(regenerated.to_bytes(), secret_key.to_bytes());
+    }
+
+    #[test]
+    fn nonzero_padding_is_rejected() {
+        type P = McEliece6960119;
+
+        let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+
+        let (mut ciphertext, _) = public_key.encapsulate();
+        ciphertext.0.as_mut()[P::C0_BYTES - 1] |= 0b1000_0000;
+
+        let mut expected_session_key = [0u8; L_BYTES];
+        crate::hash::hash_x(
+            0,
+            &secret_key.s[..P::N_BYTES],
+            ciphertext.as_ref(),
+            &mut expected_session_key,
+        );
+
+        assert_eq!(
+            secret_key.decapsulate(&ciphertext).as_bytes(),
+            &expected_session_key
+        );
+    }
+
+    // The syndrome of errors at `positions` with nonzero `weights` is the sequence of
+    // sum(weight * position^j) for j < 2T, and its locator, as reversed by `berlenkamp_massey`, is
+    // y^(T - w) times the product of (y - position) over the w positions.
+    fn berlenkamp_massey_recovers_locator<P: ParameterSet>(positions: &[u16], weights: &[u16]) {
+        let positions: Vec<FieldElement<P>> = positions.iter().map(|x| (*x).into()).collect();
+
+        let mut syndrome = vec![FieldElement::<P>::ZERO; 2 * P::T];
+        for (position, weight) in positions.iter().zip(weights.iter()) {
+            let mut term = FieldElement::from(*weight);
+            for s in syndrome.iter_mut() {
+                *s += term;
+                term *= *position;
+            }
+        }
+
+        let mut expected = vec![FieldElement::<P>::ZERO; P::T - positions.len()];
+        expected.push(FieldElement::ONE);
+        for position in positions.iter() {
+            expected.insert(0, FieldElement::ZERO);
+            for i in 0..(expected.len() - 1) {
+                let product = *position * expected[i + 1];
+                expected[i] += product;
+            }
+        }
+
+        let expected: Vec<u16> = expected[..P::T].iter().map(|x| u16::from(*x)).collect();
+
+        assert_eq!(berlenkamp_massey::<P>(&syndrome), expected[..]);
+    }
+
+    fn error_pattern<P: ParameterSet>() -> impl Strategy<Value = (Vec<u16>, Vec<u16>)> {
+        (
+            proptest::sample::subsequence((1..(P::Q as u16)).collect::<Vec<_>>(), 0..=P::T),
+            proptest::collection::vec(1..(P::Q as u16), P::T),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn berlenkamp_massey_mceliece348864((positions, weights) in error_pattern::<McEliece348864>()) {
+            berlenkamp_massey_recovers_locator::<McEliece348864>(&positions, &weights);
+        }
+
+        #[test]
+        fn berlenkamp_massey_mceliece6960119((positions, weights) in error_pattern::<McEliece6960119>()) {
+            berlenkamp_massey_recovers_locator::<McEliece6960119>(&positions, &weights);
+        }
+
+        #[test]
+        fn berlenkamp_massey_mceliece8192128((positions, weights) in error_pattern::<McEliece8192128>()) {
+            berlenkamp_massey_recovers_locator::<McEliece8192128>(&positions, &weights);
+        }
+    }
 }
@@ -0,0 +1,98 @@
+use core::fmt;
+
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::parameter_set::L_BYTES;
+
+/// A shared session key. It compares in constant time, is zeroized when dropped and is redacted
+/// in `Debug` output.
+pub struct SessionKey(pub(crate) [u8; L_BYTES]);
+
+impl SessionKey {
+    pub const BYTES: usize = L_BYTES;
+
+    #[inline]
+    pub(crate) fn zeroed() -> Self {
+        Self([0; L_BYTES])
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; L_BYTES] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for SessionKey {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for SessionKey {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for SessionKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SessionKey {}
+
+impl fmt::Debug for SessionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SessionKey(..)")
+    }
+}
+
+impl Drop for SessionKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::mem::MaybeUninit;
+    use std::ptr::{self, addr_of};
+
+    use crate::session_key::SessionKey;
+
+    #[test]
+    fn debug_is_redacted() {
+        let session_key = SessionKey([0x2a; SessionKey::BYTES]);
+
+        assert_eq!(format!("{:?}", session_key), "SessionKey(..)");
+    }
+
+    #[test]
+    fn eq() {
+        let mut other = SessionKey([0x2a; SessionKey::BYTES]);
+
+        assert_eq!(SessionKey([0x2a; SessionKey::BYTES]), other);
+
+        other.0[SessionKey::BYTES - 1] ^= 1;
+        assert_ne!(SessionKey([0x2a; SessionKey::BYTES]), other);
+    }
+
+    // Runs the actual `Drop` on a key that lives in a `MaybeUninit`, whose memory stays readable
+    // afterwards.
+    #[test]
+    fn zeroized_on_drop() {
+        let mut session_key = MaybeUninit::new(SessionKey([0x2a; SessionKey::BYTES]));
+        let session_key = session_key.as_mut_ptr();
+
+        unsafe {
+            ptr::drop_in_place(session_key);
+
+            assert_eq!(addr_of!((*session_key).0).read(), [0; SessionKey::BYTES]);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// Runs key generation, encapsulation and decapsulation with the ctgrind feature, which marks the
+// secret data as undefined memory. Under valgrind's memcheck, every branch and memory index that
+// depends on a secret is then reported as a use of uninitialised memory:
+//
+//     CARGO_TARGET_X86_64_UNKNOWN_LINUX_GNU_RUNNER="valgrind --error-exitcode=1" \
+//         cargo test --release --features ctgrind --test ctgrind
+//
+// Without valgrind this only checks that the operations agree, unless CTGRIND_REQUIRE_VALGRIND is
+// set, as in CI, in which case client requests that do not reach valgrind fail the run.
+
+use classic_mceliece::{
+    running_on_valgrind, Ciphertext, ClassicMcEliece, McEliece348864, McEliece6960119f,
+    McEliece8192128f, ParameterSet,
+};
+
+fn keygen_encapsulate_decapsulate<P: ParameterSet>() {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+
+    let (ciphertext, session_key) = public_key.encapsulate();
+    assert_eq!(secret_key.decapsulate(&ciphertext), session_key);
+
+    let mut bytes = *ciphertext.as_bytes();
+    bytes.as_mut()[0] ^= 1;
+    let ciphertext = Ciphertext::<P>::from_bytes(&bytes);
+    assert_ne!(secret_key.decapsulate(&ciphertext), session_key);
+}
+
+fn main() {
+    if std::env::var_os("CTGRIND_REQUIRE_VALGRIND").is_some() {
+        assert!(
+            running_on_valgrind(),
+            "not running under valgrind, or the client requests are not recognized"
+        );
+    }
+
+    keygen_encapsulate_decapsulate::<McEliece348864>();
+    keygen_encapsulate_decapsulate::<McEliece6960119f>();
+    keygen_encapsulate_decapsulate::<McEliece8192128f>();
+}
@@ -0,0 +1,25 @@
+#!/bin/sh -eu
+# Downloads the NIST submission of Classic McEliece and copies the kat_kem.rsp file of every
+# parameter set to tests/kats/<parameter set>.rsp, for the ignored nist_kats_* tests.
+
+url=https://classic.mceliece.org/nist/mceliece-20221023.tar.gz
+
+cd "$(dirname "$0")"
+mkdir -p kats
+
+tmp=$(mktemp -d)
+trap 'rm -rf "$tmp"' EXIT
+
+curl -fsSL "$url" | tar -xz -C "$tmp"
+
+for parameter_set in \
+    mceliece348864 mceliece348864f mceliece460896 mceliece460896f mceliece6688128 \
+    mceliece6688128f mceliece6960119 mceliece6960119f mceliece8192128 mceliece8192128f
+do
+    rsp=$(find "$tmp" -path "*/$parameter_set/kat_kem.rsp" | head -n 1)
+    if [ -z "$rsp" ]; then
+        echo "no kat_kem.rsp for $parameter_set in $url" >&2
+        exit 1
+    fi
+    cp "$rsp" "kats/$parameter_set.rsp"
+done
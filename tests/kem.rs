@@ -0,0 +1,33 @@
+#![cfg(all(feature = "kem", feature = "std"))]
+
+use kem::{Decapsulate, Encapsulate};
+
+use classic_mceliece::{ClassicMcEliece, McEliece348864, McEliece6960119f};
+
+fn key_exchange<E, D, EK, SS>(encapsulator: &E, decapsulator: &D)
+where
+    E: Encapsulate<EK, SS>,
+    D: Decapsulate<EK, SS>,
+    SS: PartialEq + std::fmt::Debug,
+{
+    let (ciphertext, expected_session_key) =
+        encapsulator.encapsulate(&mut rand::thread_rng()).unwrap();
+
+    let session_key = decapsulator.decapsulate(&ciphertext).unwrap();
+
+    assert_eq!(session_key, expected_session_key);
+}
+
+#[test]
+fn kem_traits_mceliece348864() {
+    let (secret_key, public_key) = ClassicMcEliece::<McEliece348864>::generate_keypair();
+
+    key_exchange(&public_key, &secret_key);
+}
+
+#[test]
+fn kem_traits_mceliece6960119f() {
+    let (secret_key, public_key) = ClassicMcEliece::<McEliece6960119f>::generate_keypair();
+
+    key_exchange(&public_key, &secret_key);
+}
@@ -1,9 +1,23 @@
-use classic_mceliece::ClassicMcEliece;
+#![cfg(feature = "std")]
 
-#[test]
-#[ignore = "slow in debug; stack overflow"]
-fn key_exchange() {
-    let (secret_key, public_key) = ClassicMcEliece::generate_keypair();
+use classic_mceliece::{
+    ByteArray, Ciphertext, ClassicMcEliece, McEliece348864, McEliece348864f, McEliece460896,
+    McEliece460896f, McEliece6688128, McEliece6688128f, McEliece6960119, McEliece6960119f,
+    McEliece8192128, McEliece8192128f, ParameterSet, SecretKey,
+};
+
+// Keygen, encapsulation and decapsulation must not need more than a small thread stack.
+fn on_small_stack(f: impl FnOnce() + Send + 'static) {
+    std::thread::Builder::new()
+        .stack_size(256 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+fn key_exchange<P: ParameterSet>() {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
 
     let (ciphertext, expected_session_key) = public_key.encapsulate();
 
@@ -11,3 +25,55 @@ fn key_exchange() {
 
     assert_eq!(session_key, expected_session_key);
 }
+
+macro_rules! key_exchange_tests {
+    ($($name:ident: $parameter_set:ty;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                on_small_stack(key_exchange::<$parameter_set>);
+            }
+        )*
+    };
+}
+
+key_exchange_tests! {
+    key_exchange_mceliece348864: McEliece348864;
+    key_exchange_mceliece460896: McEliece460896;
+    key_exchange_mceliece6688128: McEliece6688128;
+    key_exchange_mceliece6960119: McEliece6960119;
+    key_exchange_mceliece8192128: McEliece8192128;
+    key_exchange_mceliece348864f: McEliece348864f;
+    key_exchange_mceliece460896f: McEliece460896f;
+    key_exchange_mceliece6688128f: McEliece6688128f;
+    key_exchange_mceliece6960119f: McEliece6960119f;
+    key_exchange_mceliece8192128f: McEliece8192128f;
+}
+
+// Keys and ciphertexts can be rebuilt from bytes generically, through `ByteArray`.
+fn key_exchange_through_bytes<P: ParameterSet>() {
+    let (secret_key, public_key) = ClassicMcEliece::<P>::generate_keypair();
+
+    let (ciphertext, expected_session_key) = public_key.encapsulate();
+
+    let mut secret_key_bytes = P::SecretKeyBytes::zeroed();
+    secret_key_bytes
+        .as_mut()
+        .copy_from_slice(secret_key.to_bytes().as_ref());
+    let secret_key = SecretKey::<P>::from_bytes(&secret_key_bytes).unwrap();
+
+    let mut ciphertext_bytes = P::CiphertextBytes::zeroed();
+    ciphertext_bytes
+        .as_mut()
+        .copy_from_slice(ciphertext.as_ref());
+    let ciphertext = Ciphertext::<P>::from_bytes(&ciphertext_bytes);
+
+    let session_key = secret_key.decapsulate(&ciphertext);
+
+    assert_eq!(session_key, expected_session_key);
+}
+
+#[test]
+fn key_exchange_through_bytes_mceliece348864() {
+    on_small_stack(key_exchange_through_bytes::<McEliece348864>);
+}
@@ -0,0 +1,82 @@
+#![cfg(not(feature = "std"))]
+
+// Without `std`, the working buffers live on the stack, sized for the parameter set. These tests
+// parse a secret key and decapsulate on a thread with a fixed stack budget; exceeding it aborts the
+// test binary with a stack overflow.
+
+use classic_mceliece::{
+    ByteArray, Ciphertext, McEliece348864, McEliece348864f, McEliece460896, McEliece460896f,
+    McEliece6688128, McEliece6688128f, McEliece6960119, McEliece6960119f, McEliece8192128,
+    McEliece8192128f, ParameterSet, SecretKey,
+};
+
+fn fill(bytes: &mut [u8]) {
+    let mut x = 0x9e37_79b9_7f4a_7c15u64;
+    for byte in bytes {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        *byte = x as u8;
+    }
+}
+
+// Any control bits are accepted by `from_bytes`, so only the pivots and the range of the Goppa
+// polynomial coefficients need fixing up in the arbitrary bytes.
+fn decapsulate<P: ParameterSet>() {
+    let mut bytes = P::SecretKeyBytes::zeroed();
+    fill(bytes.as_mut());
+
+    let pivots: u64 = if P::SEMI_SYSTEMATIC {
+        0x0000_0002_efff_ffff
+    } else {
+        0xffff_ffff
+    };
+    bytes.as_mut()[32..40].copy_from_slice(&pivots.to_le_bytes());
+
+    for coefficient in bytes.as_mut()[40..][..(2 * P::T)].chunks_exact_mut(2) {
+        coefficient[1] &= (((1 << P::M) - 1) >> 8) as u8;
+    }
+
+    let secret_key = SecretKey::<P>::from_bytes(&bytes).unwrap();
+
+    let mut ciphertext = P::CiphertextBytes::zeroed();
+    fill(ciphertext.as_mut());
+
+    let session_key = secret_key.decapsulate(&Ciphertext::from_bytes(&ciphertext));
+    core::hint::black_box(session_key);
+}
+
+fn on_stack(kib: usize, f: fn()) {
+    std::thread::Builder::new()
+        .stack_size(kib * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+// The budgets leave about 16 KiB over the measured use.
+macro_rules! stack_tests {
+    ($($name:ident: $parameter_set:ty, $release_kib:expr, $debug_kib:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let kib = if cfg!(debug_assertions) { $debug_kib } else { $release_kib };
+                on_stack(kib, decapsulate::<$parameter_set>);
+            }
+        )*
+    };
+}
+
+stack_tests! {
+    decapsulate_mceliece348864: McEliece348864, 96, 132;
+    decapsulate_mceliece348864f: McEliece348864f, 96, 132;
+    decapsulate_mceliece460896: McEliece460896, 152, 188;
+    decapsulate_mceliece460896f: McEliece460896f, 152, 188;
+    decapsulate_mceliece6688128: McEliece6688128, 164, 200;
+    decapsulate_mceliece6688128f: McEliece6688128f, 164, 200;
+    decapsulate_mceliece6960119: McEliece6960119, 164, 204;
+    decapsulate_mceliece6960119f: McEliece6960119f, 164, 204;
+    decapsulate_mceliece8192128: McEliece8192128, 172, 208;
+    decapsulate_mceliece8192128f: McEliece8192128f, 172, 208;
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+// With `std`, the control bits of a secret key live on the heap and are freed when it is dropped.
+// This allocator checks, while enabled, that every block freed has been zeroized first.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use classic_mceliece::mceliece348864::ClassicMcEliece;
+
+struct CheckingAllocator;
+
+static CHECKING: AtomicBool = AtomicBool::new(false);
+static FREED: AtomicUsize = AtomicUsize::new(0);
+static FREED_NONZERO: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CheckingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if CHECKING.load(Ordering::SeqCst) {
+            FREED.fetch_add(1, Ordering::SeqCst);
+
+            // SAFETY: the block is still allocated and `layout.size()` bytes long.
+            let block = std::slice::from_raw_parts(ptr, layout.size());
+            if block.iter().any(|byte| *byte != 0) {
+                FREED_NONZERO.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CheckingAllocator = CheckingAllocator;
+
+#[test]
+fn heap_is_zeroized_on_drop() {
+    let (secret_key, _public_key) = ClassicMcEliece::generate_keypair();
+
+    CHECKING.store(true, Ordering::SeqCst);
+    drop(secret_key);
+    CHECKING.store(false, Ordering::SeqCst);
+
+    assert!(FREED.load(Ordering::SeqCst) > 0);
+    assert_eq!(FREED_NONZERO.load(Ordering::SeqCst), 0);
+}